@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day2::{
+    bytes::parse_game_bytes,
+    generate::{generate, GeneratorConfig},
+    parse::parse_game,
+};
+use std::hint::black_box;
+
+fn parsers(c: &mut Criterion) {
+    let input = generate(&GeneratorConfig {
+        games: 10_000,
+        max_draws: 20,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let mut group = c.benchmark_group("parse_game");
+    group.bench_function("nom_str", |b| {
+        b.iter(|| {
+            for line in black_box(&input).lines() {
+                black_box(parse_game(line).unwrap());
+            }
+        })
+    });
+    group.bench_function("bytes", |b| {
+        b.iter(|| {
+            for line in black_box(&input).as_bytes().split(|&c| c == b'\n') {
+                if !line.is_empty() {
+                    black_box(parse_game_bytes(line).unwrap());
+                }
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parsers);
+criterion_main!(benches);
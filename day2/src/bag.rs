@@ -0,0 +1,113 @@
+use crate::game::{Dice, Game};
+use itertools::Itertools;
+
+pub fn minimum_bag(games: &[Game]) -> Dice {
+    games
+        .iter()
+        .map(Game::most_dice_shown)
+        .fold(Dice::default(), |bag, dice| bag.max(&dice))
+}
+
+pub fn feasible_games<'a>(games: &'a [Game], bag: &Dice) -> Vec<&'a Game> {
+    games.iter().filter(|game| game.is_possible(bag)).collect()
+}
+
+pub fn smallest_bag_for(games: &[Game], k: usize) -> Option<Dice> {
+    if k > games.len() {
+        return None;
+    }
+    if k == 0 {
+        return Some(Dice::default());
+    }
+    let most_shown: Vec<Dice> = games.iter().map(Game::most_dice_shown).collect();
+    let reds: Vec<u32> = most_shown
+        .iter()
+        .map(|dice| dice.red)
+        .sorted()
+        .dedup()
+        .collect();
+    let greens: Vec<u32> = most_shown
+        .iter()
+        .map(|dice| dice.green)
+        .sorted()
+        .dedup()
+        .collect();
+
+    let mut best: Option<Dice> = None;
+    for &red in &reds {
+        for &green in &greens {
+            let mut blues: Vec<u32> = most_shown
+                .iter()
+                .filter(|dice| dice.red <= red && dice.green <= green)
+                .map(|dice| dice.blue)
+                .collect();
+            if blues.len() < k {
+                continue;
+            }
+            let (_, &mut blue, _) = blues.select_nth_unstable(k - 1);
+            let bag = Dice { red, green, blue };
+            if best.is_none_or(|best| bag.total() < best.total()) {
+                best = Some(bag);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_game;
+    use rstest::*;
+
+    #[fixture]
+    fn sample_games() -> Vec<Game> {
+        include_str!("../puzzle_inputs/part1_sample.txt")
+            .lines()
+            .map(|line| parse_game(line).unwrap().1)
+            .collect()
+    }
+
+    #[rstest]
+    fn test_minimum_bag(sample_games: Vec<Game>) {
+        assert_eq!(
+            minimum_bag(&sample_games),
+            Dice {
+                red: 20,
+                green: 13,
+                blue: 15
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(Dice { red: 12, green: 13, blue: 14 }, vec![1, 2, 5])]
+    #[case(Dice { red: 20, green: 13, blue: 15 }, vec![1, 2, 3, 4, 5])]
+    #[case(Dice { red: 0, green: 0, blue: 0 }, vec![])]
+    fn test_feasible_games(
+        sample_games: Vec<Game>,
+        #[case] bag: Dice,
+        #[case] expected_numbers: Vec<u32>,
+    ) {
+        let numbers: Vec<u32> = feasible_games(&sample_games, &bag)
+            .iter()
+            .map(|game| game.number)
+            .collect();
+        assert_eq!(numbers, expected_numbers);
+    }
+
+    #[rstest]
+    #[case(0, Some(Dice { red: 0, green: 0, blue: 0 }))]
+    #[case(1, Some(Dice { red: 1, green: 3, blue: 4 }))]
+    #[case(2, Some(Dice { red: 4, green: 3, blue: 6 }))]
+    #[case(3, Some(Dice { red: 6, green: 3, blue: 6 }))]
+    #[case(5, Some(Dice { red: 20, green: 13, blue: 15 }))]
+    #[case(6, None)]
+    fn test_smallest_bag_for(
+        sample_games: Vec<Game>,
+        #[case] k: usize,
+        #[case] expected: Option<Dice>,
+    ) {
+        assert_eq!(smallest_bag_for(&sample_games, k), expected);
+    }
+}
@@ -0,0 +1,20 @@
+use anyhow::{anyhow, Result};
+use day2::{game::Dice, spanned::parse_spanned_games};
+use std::{env, fs::read_to_string};
+
+fn main() -> Result<()> {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "puzzle_inputs/part1.txt".to_string());
+    let input = read_to_string(&path)?;
+    let max_dice = Dice {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    let games = parse_spanned_games(&input).map_err(|err| anyhow!("{path}:{err}"))?;
+    for violation in games.iter().flat_map(|game| game.violations(&max_dice)) {
+        println!("{path}:{violation}");
+    }
+    Ok(())
+}
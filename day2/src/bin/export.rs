@@ -0,0 +1,26 @@
+use anyhow::{bail, Result};
+use day2::{
+    export::{write_csv, write_json_lines},
+    game::Game,
+    parse::{parse_game, ParseGameError},
+};
+use itertools::Itertools;
+use std::{env, fs::read_to_string, io::stdout};
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let format = args.next().unwrap_or_else(|| "json".to_string());
+    let path = args
+        .next()
+        .unwrap_or_else(|| "puzzle_inputs/part1.txt".to_string());
+    let input = read_to_string(path)?;
+    let games: Vec<Game> = input
+        .lines()
+        .map(|line| parse_game(line).map_err(|err| err.map(ParseGameError::into_owned)))
+        .process_results(|games| games.map(|(_, game)| game).collect())?;
+    match format.as_str() {
+        "json" => write_json_lines(&games, stdout().lock()),
+        "csv" => write_csv(&games, stdout().lock()),
+        _ => bail!("Unknown export format {format}, expected json or csv."),
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::{ensure, Result};
+use day2::{
+    game::Dice,
+    generate::{generate, GeneratorConfig},
+};
+use std::env;
+
+fn parse_bag(bag: &str) -> Result<Dice> {
+    let counts = bag
+        .split(',')
+        .map(|count| count.trim().parse())
+        .collect::<Result<Vec<u32>, _>>()?;
+    ensure!(
+        counts.len() == 3,
+        "Expected the bag as red,green,blue counts, got {bag}."
+    );
+    Ok(Dice {
+        red: counts[0],
+        green: counts[1],
+        blue: counts[2],
+    })
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut config = GeneratorConfig::default();
+    if let Some(games) = args.first() {
+        config.games = games.parse()?;
+    }
+    if let Some(seed) = args.get(1) {
+        config.seed = seed.parse()?;
+    }
+    if let Some(malformed_rate) = args.get(2) {
+        config.malformed_rate = malformed_rate.parse()?;
+    }
+    if let Some(max_draws) = args.get(3) {
+        config.max_draws = max_draws.parse()?;
+    }
+    if let Some(bag) = args.get(4) {
+        config.bag = parse_bag(bag)?;
+    }
+    print!("{}", generate(&config)?);
+    Ok(())
+}
@@ -1,13 +1,13 @@
 use anyhow::{ensure, Result};
 use day2::{
-    game::{Dice, Game},
-    parse::parse_game,
+    game::Dice,
+    stream::{summaries, GameSummary},
 };
 use itertools::Itertools;
-use std::fs::read_to_string;
+use std::{fs::File, io::BufReader};
 
-pub fn validate(game: Game, max_dice: &Dice) -> Result<Game> {
-    let Dice { red, green, blue } = game.most_dice_shown();
+pub fn validate(game: GameSummary, max_dice: &Dice) -> Result<GameSummary> {
+    let Dice { red, green, blue } = game.most_dice_shown;
     ensure!(
         red <= max_dice.red,
         format!(
@@ -33,21 +33,18 @@ pub fn validate(game: Game, max_dice: &Dice) -> Result<Game> {
 }
 
 fn main() -> Result<()> {
-    let input = read_to_string("puzzle_inputs/part1.txt")?;
+    let input = BufReader::new(File::open("puzzle_inputs/part1.txt")?);
     let max_dice = Dice {
         red: 12,
         green: 13,
         blue: 14,
     };
-    let possible_sum: u32 = input
-        .lines()
-        .map(|line| parse_game(line).map_err(|err| err.to_owned()))
-        .process_results(|games| {
-            games
-                .filter_map(|(_, game)| validate(game, &max_dice).ok())
-                .map(|game| game.number)
-                .sum()
-        })?;
+    let possible_sum: u32 = summaries(input).process_results(|games| {
+        games
+            .filter_map(|game| validate(game, &max_dice).ok())
+            .map(|game| game.number)
+            .sum()
+    })?;
     println!("{possible_sum}");
     Ok(())
 }
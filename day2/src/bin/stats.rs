@@ -0,0 +1,34 @@
+use anyhow::Result;
+use day2::{
+    game::{Dice, Game},
+    parse::{parse_game, ParseGameError},
+    stats::GameStats,
+};
+use itertools::Itertools;
+use std::{env, fs::read_to_string};
+
+fn main() -> Result<()> {
+    let (flags, paths): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|arg| arg.starts_with("--"));
+    let json = flags.iter().any(|flag| flag == "--json");
+    let path = paths
+        .first()
+        .map_or("puzzle_inputs/part1.txt", String::as_str);
+    let input = read_to_string(path)?;
+    let games: Vec<Game> = input
+        .lines()
+        .map(|line| parse_game(line).map_err(|err| err.map(ParseGameError::into_owned)))
+        .process_results(|games| games.map(|(_, game)| game).collect())?;
+    let max_dice = Dice {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    let stats = GameStats::new(&games, &max_dice);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        println!("{stats}");
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::game::{Color, Dice, Game};
+use crate::stream::GameSummary;
+use nom::{
+    bytes::complete::{tag, take_till},
+    character::complete::{digit1, multispace0, newline},
+    combinator::{map_opt, opt},
+    multi::{fold_many1, many1},
+    sequence::{delimited, terminated},
+    IResult,
+};
+
+fn digits_to_u32(digits: &[u8]) -> Option<u32> {
+    let digits = digits.strip_prefix(b"+").unwrap_or(digits);
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0u32, |number, digit| {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        number.checked_mul(10)?.checked_add(u32::from(digit - b'0'))
+    })
+}
+
+fn color(name: &[u8]) -> Option<Color> {
+    match name {
+        b"red" => Some(Color::Red),
+        b"green" => Some(Color::Green),
+        b"blue" => Some(Color::Blue),
+        _ => None,
+    }
+}
+
+pub(crate) fn game_number(input: &[u8]) -> IResult<&[u8], u32> {
+    let (input, _) = tag("Game ")(input)?;
+    map_opt(take_till(|c| c == b':'), digits_to_u32)(input)
+}
+
+fn alphabetic(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    let text = match std::str::from_utf8(input) {
+        Ok(text) => text,
+        Err(err) => std::str::from_utf8(&input[..err.valid_up_to()]).unwrap_or_default(),
+    };
+    let len = text
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(text.len());
+    Ok((&input[len..], &input[..len]))
+}
+
+fn quantity_color(input: &[u8]) -> IResult<&[u8], (u32, Color)> {
+    let (input, quantity) =
+        map_opt(delimited(multispace0, digit1, multispace0), digits_to_u32)(input)?;
+    let (input, color) = terminated(map_opt(alphabetic, color), opt(tag(",")))(input)?;
+    Ok((input, (quantity, color)))
+}
+
+pub(crate) fn parse_draw(input: &[u8]) -> IResult<&[u8], Dice> {
+    let (input, (dice, _)) = terminated(
+        fold_many1(
+            quantity_color,
+            || (Dice::default(), [false; 3]),
+            |(mut dice, mut seen), (quantity, color)| {
+                let index = color as usize;
+                if !seen[index] {
+                    seen[index] = true;
+                    *dice.count_mut(color) = quantity;
+                }
+                (dice, seen)
+            },
+        ),
+        opt(tag(";")),
+    )(input)?;
+    Ok((input, dice))
+}
+
+pub fn parse_game_bytes(input: &[u8]) -> IResult<&[u8], Game> {
+    let (input, number) = game_number(input)?;
+    let (remains, draws) = delimited(tag(":"), many1(parse_draw), opt(newline))(input)?;
+    Ok((remains, Game { number, draws }))
+}
+
+pub fn summarize_game_bytes(input: &[u8]) -> IResult<&[u8], GameSummary> {
+    let (input, number) = game_number(input)?;
+    let (remains, (most_dice_shown, draws)) = delimited(
+        tag(":"),
+        fold_many1(
+            parse_draw,
+            || (Dice::default(), 0),
+            |(most, draws), draw| (most.max(&draw), draws + 1),
+        ),
+        opt(newline),
+    )(input)?;
+    Ok((
+        remains,
+        GameSummary {
+            number,
+            most_dice_shown,
+            draws,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(b"0", Some(0))]
+    #[case(b"4294967295", Some(u32::MAX))]
+    #[case(b"4294967296", None)]
+    #[case(b"+7", Some(7))]
+    #[case(b"", None)]
+    #[case(b"1x", None)]
+    fn test_digits_to_u32(#[case] digits: &[u8], #[case] expected: Option<u32>) {
+        assert_eq!(digits_to_u32(digits), expected);
+    }
+
+    #[rstest]
+    #[case(b"3 red, 2 blue, 1 green; 4 red", Dice { red: 3, blue: 2, green: 1 }, b" 4 red")]
+    #[case(b"3 red, 2 blue, 5 red", Dice { red: 3, blue: 2, green: 0 }, b"")]
+    fn test_parse_draw(
+        #[case] input: &[u8],
+        #[case] expected: Dice,
+        #[case] expected_remains: &[u8],
+    ) {
+        let (remains, draw) = parse_draw(input).unwrap();
+        assert_eq!(draw, expected);
+        assert_eq!(remains, expected_remains);
+    }
+}
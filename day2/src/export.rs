@@ -0,0 +1,94 @@
+use crate::game::{Dice, Game};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DrawRecord {
+    pub game: u32,
+    pub draw: usize,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+pub fn draw_records(games: &[Game]) -> impl Iterator<Item = DrawRecord> + '_ {
+    games.iter().flat_map(|game| {
+        game.draws
+            .iter()
+            .enumerate()
+            .map(|(draw, &Dice { red, green, blue })| DrawRecord {
+                game: game.number,
+                draw,
+                red,
+                green,
+                blue,
+            })
+    })
+}
+
+pub fn write_json_lines<W: Write>(games: &[Game], mut writer: W) -> Result<()> {
+    for game in games {
+        serde_json::to_writer(&mut writer, game)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(games: &[Game], writer: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for record in draw_records(games) {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_game;
+    use rstest::*;
+
+    #[fixture]
+    fn games() -> Vec<Game> {
+        [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green",
+        ]
+        .iter()
+        .map(|line| parse_game(line).unwrap().1)
+        .collect()
+    }
+
+    #[rstest]
+    fn test_write_json_lines(games: Vec<Game>) {
+        let mut output = vec![];
+        write_json_lines(&games, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output,
+            concat!(
+                r#"{"number":1,"draws":[{"red":4,"green":0,"blue":3},{"red":1,"green":2,"blue":6},{"red":0,"green":2,"blue":0}]}"#,
+                "\n",
+                r#"{"number":2,"draws":[{"red":0,"green":2,"blue":1}]}"#,
+                "\n",
+            )
+        );
+        let round_tripped: Vec<Game> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(round_tripped, games);
+    }
+
+    #[rstest]
+    fn test_write_csv(games: Vec<Game>) {
+        let mut output = vec![];
+        write_csv(&games, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "game,draw,red,green,blue\n1,0,4,0,3\n1,1,1,2,6\n1,2,0,2,0\n2,0,0,2,1\n"
+        );
+    }
+}
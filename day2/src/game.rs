@@ -1,23 +1,46 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Game {
     pub number: u32,
     pub draws: Vec<Dice>,
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, Default, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Dice {
     pub red: u32,
     pub green: u32,
     pub blue: u32,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RawGame {
+    pub number: u32,
+    pub draws: Vec<Draw>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Draw {
+    pub quantity_colors: Vec<(u32, Color)>,
+}
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum DuplicatePolicy {
+    Sum,
+    Reject,
+    #[default]
+    KeepFirst,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Color {
-    Red,
-    Green,
-    Blue,
+    Red = 0,
+    Green = 1,
+    Blue = 2,
 }
 
 impl Game {
@@ -38,32 +61,86 @@ impl Game {
         });
         Dice { red, green, blue }
     }
+
+    pub fn is_possible(&self, bag: &Dice) -> bool {
+        bag.can_hold(&self.most_dice_shown())
+    }
 }
 
-impl From<Vec<(u32, Color)>> for Dice {
-    fn from(quantity_colors: Vec<(u32, Color)>) -> Self {
-        let red = match quantity_colors
-            .iter()
-            .find(|(_, color)| *color == Color::Red)
-        {
-            Some((quantity, _)) => *quantity,
-            None => 0,
-        };
-        let green = match quantity_colors
-            .iter()
-            .find(|(_, color)| *color == Color::Green)
-        {
-            Some((quantity, _)) => *quantity,
-            None => 0,
-        };
-        let blue = match quantity_colors
+impl Dice {
+    pub fn total(&self) -> u32 {
+        self.red + self.green + self.blue
+    }
+
+    pub fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+
+    pub fn can_hold(&self, other: &Dice) -> bool {
+        self.red >= other.red && self.green >= other.green && self.blue >= other.blue
+    }
+
+    pub fn max(&self, other: &Dice) -> Dice {
+        Dice {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+}
+
+impl RawGame {
+    pub fn to_game(&self, policy: DuplicatePolicy) -> Result<Game> {
+        let draws = self
+            .draws
             .iter()
-            .find(|(_, color)| *color == Color::Blue)
-        {
-            Some((quantity, _)) => *quantity,
-            None => 0,
-        };
-        Self { red, green, blue }
+            .map(|draw| draw.to_dice(policy))
+            .collect::<Result<_>>()?;
+        Ok(Game {
+            number: self.number,
+            draws,
+        })
+    }
+}
+
+impl Draw {
+    pub fn to_dice(&self, policy: DuplicatePolicy) -> Result<Dice> {
+        let mut dice = Dice::default();
+        let mut seen = [false; 3];
+        for &(quantity, color) in &self.quantity_colors {
+            let count = dice.count_mut(color);
+            if !seen[color as usize] {
+                seen[color as usize] = true;
+                *count = quantity;
+                continue;
+            }
+            match policy {
+                DuplicatePolicy::Sum => *count += quantity,
+                DuplicatePolicy::Reject => {
+                    bail!("{color} is listed more than once in draw \"{self}\".")
+                }
+                DuplicatePolicy::KeepFirst => {}
+            }
+        }
+        Ok(dice)
+    }
+}
+
+impl Dice {
+    pub fn count(&self, color: Color) -> u32 {
+        match color {
+            Color::Red => self.red,
+            Color::Green => self.green,
+            Color::Blue => self.blue,
+        }
+    }
+
+    pub fn count_mut(&mut self, color: Color) -> &mut u32 {
+        match color {
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+            Color::Blue => &mut self.blue,
+        }
     }
 }
 
@@ -77,3 +154,53 @@ impl Color {
         }
     }
 }
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game {}: {}", self.number, self.draws.iter().join("; "))
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let quantity_colors = [
+            (self.red, Color::Red),
+            (self.green, Color::Green),
+            (self.blue, Color::Blue),
+        ];
+        let shown = quantity_colors
+            .iter()
+            .filter(|(quantity, _)| *quantity > 0)
+            .map(|(quantity, color)| format!("{quantity} {color}"))
+            .join(", ");
+        write!(f, "{shown}")
+    }
+}
+
+impl fmt::Display for RawGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game {}: {}", self.number, self.draws.iter().join("; "))
+    }
+}
+
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shown = self
+            .quantity_colors
+            .iter()
+            .map(|(quantity, color)| format!("{quantity} {color}"))
+            .join(", ");
+        write!(f, "{shown}")
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+        };
+        write!(f, "{name}")
+    }
+}
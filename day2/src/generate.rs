@@ -0,0 +1,226 @@
+use crate::game::{Color, Dice, Draw, RawGame};
+use anyhow::{anyhow, ensure, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+#[derive(Debug, Clone)]
+pub struct GeneratorConfig {
+    pub bag: Dice,
+    pub games: u32,
+    pub max_draws: usize,
+    pub malformed_rate: f64,
+    pub seed: u64,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            bag: Dice {
+                red: 12,
+                green: 13,
+                blue: 14,
+            },
+            games: 100,
+            max_draws: 6,
+            malformed_rate: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+fn bag_size(bag: &Dice) -> Result<u32> {
+    let size = bag
+        .red
+        .checked_add(bag.green)
+        .and_then(|size| size.checked_add(bag.blue))
+        .ok_or_else(|| anyhow!("Bag {bag} holds more than {} dice.", u32::MAX))?;
+    ensure!(size > 0, "Cannot generate games from an empty bag.");
+    Ok(size)
+}
+
+pub fn generate(config: &GeneratorConfig) -> Result<String> {
+    bag_size(&config.bag)?;
+    ensure!(
+        (0.0..=1.0).contains(&config.malformed_rate),
+        "Malformed rate {} is not between 0 and 1.",
+        config.malformed_rate
+    );
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut output = String::new();
+    for number in 1..=config.games {
+        let game = random_game(&mut rng, number, &config.bag, config.max_draws)?;
+        let line = if rng.gen_bool(config.malformed_rate) {
+            malform(&mut rng, &game)
+        } else {
+            game.to_string()
+        };
+        output.push_str(&line);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+pub fn random_game<R: Rng>(
+    rng: &mut R,
+    number: u32,
+    bag: &Dice,
+    max_draws: usize,
+) -> Result<RawGame> {
+    let draw_count = rng.gen_range(1..=max_draws.max(1));
+    let draws = (0..draw_count)
+        .map(|_| random_draw(rng, bag))
+        .collect::<Result<_>>()?;
+    Ok(RawGame { number, draws })
+}
+
+fn only_color(dice: &Dice) -> Option<Color> {
+    let mut colors = [Color::Red, Color::Green, Color::Blue]
+        .into_iter()
+        .filter(|&color| dice.count(color) > 0);
+    match (colors.next(), colors.next()) {
+        (Some(color), None) => Some(color),
+        _ => None,
+    }
+}
+
+fn add_dice(quantity_colors: &mut Vec<(u32, Color)>, color: Color, quantity: u32) {
+    match quantity_colors.iter_mut().find(|(_, seen)| *seen == color) {
+        Some((count, _)) => *count += quantity,
+        None => quantity_colors.push((quantity, color)),
+    }
+}
+
+pub fn random_draw<R: Rng>(rng: &mut R, bag: &Dice) -> Result<Draw> {
+    let mut left = bag_size(bag)?;
+    let handful = rng.gen_range(1..=left);
+    let mut remaining = *bag;
+    let mut quantity_colors: Vec<(u32, Color)> = vec![];
+    for drawn in 0..handful {
+        if let Some(color) = only_color(&remaining) {
+            add_dice(&mut quantity_colors, color, handful - drawn);
+            break;
+        }
+        let mut pick = rng.gen_range(0..left);
+        let color = [Color::Red, Color::Green, Color::Blue]
+            .into_iter()
+            .find(|&color| {
+                let count = remaining.count(color);
+                if pick < count {
+                    return true;
+                }
+                pick -= count;
+                false
+            })
+            .expect("pick is below the number of dice left");
+        *remaining.count_mut(color) -= 1;
+        left -= 1;
+        add_dice(&mut quantity_colors, color, 1);
+    }
+    quantity_colors.shuffle(rng);
+    Ok(Draw { quantity_colors })
+}
+
+fn malform<R: Rng>(rng: &mut R, game: &RawGame) -> String {
+    let line = game.to_string();
+    match rng.gen_range(0..4) {
+        0 => line.replacen(':', "", 1),
+        1 => line.replacen("Game ", "Game x", 1),
+        2 => {
+            let color = ["red", "green", "blue"]
+                .into_iter()
+                .find(|color| line.contains(color))
+                .unwrap_or("red");
+            line.replacen(color, "purple", 1)
+        }
+        _ => format!(
+            "Game {}: red{}",
+            game.number,
+            &line[line.find(':').unwrap() + 1..]
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_raw_game;
+    use rstest::*;
+
+    #[rstest]
+    #[case(0)]
+    #[case(42)]
+    fn test_generate_is_seeded(#[case] seed: u64) {
+        let config = GeneratorConfig {
+            seed,
+            malformed_rate: 0.2,
+            ..Default::default()
+        };
+        assert_eq!(generate(&config).unwrap(), generate(&config).unwrap());
+    }
+
+    #[rstest]
+    #[case(Dice { red: 12, green: 13, blue: 14 })]
+    #[case(Dice { red: 0, green: 1, blue: 0 })]
+    fn test_generated_games_fit_bag(#[case] bag: Dice) {
+        let config = GeneratorConfig {
+            bag,
+            games: 200,
+            ..Default::default()
+        };
+        let output = generate(&config).unwrap();
+        assert_eq!(output.lines().count(), 200);
+        for (line, number) in output.lines().zip(1..) {
+            let (remains, raw_game) = parse_raw_game(line).unwrap();
+            assert_eq!(remains, "");
+            assert_eq!(raw_game.number, number);
+            assert!(raw_game.draws.len() <= config.max_draws);
+            for draw in &raw_game.draws {
+                assert!(!draw.quantity_colors.is_empty());
+                let colors: Vec<Color> = draw.quantity_colors.iter().map(|(_, c)| *c).collect();
+                assert!((1..colors.len()).all(|i| !colors[..i].contains(&colors[i])));
+            }
+            let game = raw_game.to_game(Default::default()).unwrap();
+            assert!(game.is_possible(&bag));
+        }
+    }
+
+    #[rstest]
+    #[case(Dice::default(), 0.0)]
+    #[case(Dice { red: u32::MAX, green: 1, blue: 0 }, 0.0)]
+    #[case(Dice { red: 1, green: 0, blue: 0 }, -0.1)]
+    #[case(Dice { red: 1, green: 0, blue: 0 }, 1.5)]
+    fn test_generate_rejects_invalid_config(#[case] bag: Dice, #[case] malformed_rate: f64) {
+        let config = GeneratorConfig {
+            bag,
+            malformed_rate,
+            ..Default::default()
+        };
+        assert!(generate(&config).is_err());
+    }
+
+    #[rstest]
+    fn test_random_draw_from_large_bag() {
+        let bag = Dice {
+            red: u32::MAX - 2,
+            green: 1,
+            blue: 1,
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let draw = random_draw(&mut rng, &Dice { red: 3, ..bag }).unwrap();
+        let dice = draw.to_dice(Default::default()).unwrap();
+        assert!(dice.total() >= 1 && bag.can_hold(&dice));
+        assert!(random_draw(&mut rng, &Dice::default()).is_err());
+        assert!(random_draw(&mut rng, &Dice { blue: 3, ..bag }).is_err());
+    }
+
+    #[rstest]
+    fn test_malformed_lines_fail_to_parse() {
+        let config = GeneratorConfig {
+            malformed_rate: 1.0,
+            ..Default::default()
+        };
+        for line in generate(&config).unwrap().lines() {
+            let parsed = matches!(parse_raw_game(line), Ok((remains, _)) if remains.is_empty());
+            assert!(!parsed, "{line} parsed");
+        }
+    }
+}
@@ -1,2 +1,10 @@
+pub mod bag;
+pub mod bytes;
+pub mod export;
 pub mod game;
+pub mod generate;
+pub mod likelihood;
 pub mod parse;
+pub mod spanned;
+pub mod stats;
+pub mod stream;
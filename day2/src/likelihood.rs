@@ -0,0 +1,97 @@
+use crate::game::{Dice, Game};
+
+fn ln_choose(n: u32, k: u32) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    let k = k.min(n - k);
+    (0..k)
+        .map(|i| (f64::from(n - i) / f64::from(i + 1)).ln())
+        .sum()
+}
+
+impl Dice {
+    pub fn draw_likelihood(&self, bag: &Dice) -> f64 {
+        if !bag.can_hold(self) {
+            return f64::NEG_INFINITY;
+        }
+        ln_choose(bag.red, self.red)
+            + ln_choose(bag.green, self.green)
+            + ln_choose(bag.blue, self.blue)
+            - ln_choose(bag.total(), self.total())
+    }
+}
+
+impl Game {
+    pub fn likelihood(&self, bag: &Dice) -> f64 {
+        self.draws
+            .iter()
+            .map(|draw| draw.draw_likelihood(bag))
+            .sum()
+    }
+}
+
+pub fn max_likelihood_bag(games: &[Game], max_total: u32) -> Option<(Dice, f64)> {
+    let minimum = crate::bag::minimum_bag(games);
+    let mut best: Option<(Dice, f64)> = None;
+    for red in minimum.red..=max_total {
+        for green in minimum.green..=max_total.saturating_sub(red) {
+            for blue in minimum.blue..=max_total.saturating_sub(red + green) {
+                let bag = Dice { red, green, blue };
+                let likelihood: f64 = games.iter().map(|game| game.likelihood(&bag)).sum();
+                if likelihood.is_finite()
+                    && best.is_none_or(|(_, best_likelihood)| likelihood > best_likelihood)
+                {
+                    best = Some((bag, likelihood));
+                }
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_game;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Dice { red: 0, green: 0, blue: 3 }, Dice { red: 0, green: 0, blue: 3 }, 0.0)]
+    #[case(Dice { red: 1, green: 0, blue: 0 }, Dice { red: 1, green: 0, blue: 1 }, 0.5_f64.ln())]
+    #[case(Dice { red: 1, green: 1, blue: 0 }, Dice { red: 2, green: 2, blue: 0 }, (4.0_f64 / 6.0).ln())]
+    #[case(Dice { red: 2, green: 0, blue: 0 }, Dice { red: 1, green: 5, blue: 5 }, f64::NEG_INFINITY)]
+    fn test_draw_likelihood(#[case] draw: Dice, #[case] bag: Dice, #[case] expected: f64) {
+        let likelihood = draw.draw_likelihood(&bag);
+        if expected.is_finite() {
+            assert!((likelihood - expected).abs() < 1e-12);
+        } else {
+            assert_eq!(likelihood, expected);
+        }
+    }
+
+    #[rstest]
+    #[case("Game 1: 1 red; 1 blue", Dice { red: 1, green: 0, blue: 1 }, 0.25_f64.ln())]
+    #[case("Game 2: 3 blue, 4 red; 2 green", Dice { red: 4, green: 2, blue: 3 }, (1.0_f64 / 36.0 * 1.0 / 36.0).ln())]
+    fn test_game_likelihood(#[case] input: &str, #[case] bag: Dice, #[case] expected: f64) {
+        let (_, game) = parse_game(input).unwrap();
+        assert!((game.likelihood(&bag) - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    #[case(vec!["Game 1: 2 red; 2 red"], 10, Some(Dice { red: 2, green: 0, blue: 0 }))]
+    #[case(vec!["Game 1: 1 red, 1 blue", "Game 2: 1 blue, 1 red"], 10, Some(Dice { red: 1, green: 0, blue: 1 }))]
+    #[case(vec!["Game 1: 6 red"], 5, None)]
+    fn test_max_likelihood_bag(
+        #[case] inputs: Vec<&str>,
+        #[case] max_total: u32,
+        #[case] expected: Option<Dice>,
+    ) {
+        let games: Vec<Game> = inputs
+            .iter()
+            .map(|input| parse_game(input).unwrap().1)
+            .collect();
+        let estimate = max_likelihood_bag(&games, max_total);
+        assert_eq!(estimate.map(|(bag, _)| bag), expected);
+    }
+}
@@ -1,45 +1,133 @@
-use crate::game::{Color, Dice, Game};
+use crate::game::{Color, Dice, Draw, DuplicatePolicy, Game, RawGame};
 use nom::{
     bytes::complete::{tag, take_till, take_while},
     character::complete::{digit1, multispace0, newline},
-    combinator::opt,
+    combinator::{map_res, opt},
+    error::{ErrorKind, FromExternalError, ParseError},
     multi::many1,
     sequence::{delimited, terminated},
     IResult,
 };
+use std::fmt;
 
-fn game_number(input: &str) -> IResult<&str, u32> {
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseGameError<I> {
+    pub input: I,
+    pub kind: ErrorKind,
+    pub message: Option<String>,
+}
+
+type ParseResult<'a, O> = IResult<&'a str, O, ParseGameError<&'a str>>;
+
+impl ParseGameError<&str> {
+    pub fn into_owned(self) -> ParseGameError<String> {
+        ParseGameError {
+            input: self.input.to_string(),
+            kind: self.kind,
+            message: self.message,
+        }
+    }
+}
+
+impl<I> ParseError<I> for ParseGameError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            message: None,
+        }
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I, E: fmt::Display> FromExternalError<I, E> for ParseGameError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, error: E) -> Self {
+        Self {
+            input,
+            kind,
+            message: Some(error.to_string()),
+        }
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for ParseGameError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message}"),
+            None => write!(f, "error {:?} at: {}", self.kind, self.input),
+        }
+    }
+}
+
+impl<I: fmt::Debug + fmt::Display> std::error::Error for ParseGameError<I> {}
+
+fn game_number(input: &str) -> ParseResult<'_, u32> {
     let (input, _) = tag("Game ")(input)?;
-    let (input, number) = take_till(|c| c == ':')(input)?;
-    Ok((input, number.parse::<u32>().unwrap()))
+    let (input, number) = map_res(take_till(|c| c == ':'), str::parse::<u32>)(input)?;
+    Ok((input, number))
 }
 
-fn quantity_color(input: &str) -> IResult<&str, (u32, Color)> {
-    let (input, quantity) = delimited(multispace0, digit1, multispace0)(input)?;
-    let quantity = quantity.parse::<u32>().unwrap();
-    let (input, color) = terminated(take_while(char::is_alphabetic), opt(tag(",")))(input)?;
-    let color = Color::parse(color).unwrap();
+fn quantity_color(input: &str) -> ParseResult<'_, (u32, Color)> {
+    let (input, quantity) = map_res(
+        delimited(multispace0, digit1, multispace0),
+        str::parse::<u32>,
+    )(input)?;
+    let (input, color) = terminated(
+        map_res(take_while(char::is_alphabetic), Color::parse),
+        opt(tag(",")),
+    )(input)?;
     Ok((input, (quantity, color)))
 }
 
-fn quantity_color_multiple(input: &str) -> IResult<&str, Vec<(u32, Color)>> {
+fn quantity_color_multiple(input: &str) -> ParseResult<'_, Vec<(u32, Color)>> {
     many1(quantity_color)(input)
 }
 
-fn parse_draw(input: &str) -> IResult<&str, Dice> {
+fn parse_raw_draw(input: &str) -> ParseResult<'_, Draw> {
     let (input, quantity_colors) = terminated(quantity_color_multiple, opt(tag(";")))(input)?;
-    Ok((input, Dice::from(quantity_colors)))
+    Ok((input, Draw { quantity_colors }))
+}
+
+fn parse_draw_with(policy: DuplicatePolicy) -> impl Fn(&str) -> ParseResult<'_, Dice> {
+    move |input| {
+        let (remains, draw) = parse_raw_draw(input)?;
+        let dice = draw.to_dice(policy).map_err(|err| {
+            nom::Err::Failure(ParseGameError::from_external_error(
+                input,
+                ErrorKind::Verify,
+                err,
+            ))
+        })?;
+        Ok((remains, dice))
+    }
+}
+
+pub fn parse_game(input: &str) -> ParseResult<'_, Game> {
+    parse_game_with(DuplicatePolicy::default())(input)
 }
 
-pub fn parse_game(input: &str) -> IResult<&str, Game> {
+pub fn parse_game_with(policy: DuplicatePolicy) -> impl Fn(&str) -> ParseResult<'_, Game> {
+    move |input| {
+        let (input, number) = game_number(input)?;
+        let (remains, draws) =
+            delimited(tag(":"), many1(parse_draw_with(policy)), opt(newline))(input)?;
+        Ok((remains, Game { number, draws }))
+    }
+}
+
+pub fn parse_raw_game(input: &str) -> ParseResult<'_, RawGame> {
     let (input, number) = game_number(input)?;
-    let (remains, draws) = delimited(tag(":"), many1(parse_draw), opt(newline))(input)?;
-    Ok((remains, Game { number, draws }))
+    let (remains, draws) = delimited(tag(":"), many1(parse_raw_draw), opt(newline))(input)?;
+    Ok((remains, RawGame { number, draws }))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     use rstest::*;
 
     #[rstest]
@@ -85,7 +173,7 @@ mod tests {
     #[rstest]
     #[case("3 red, 2 blue, 1 green", Dice { red: 3, blue: 2, green: 1})]
     fn test_parse_draw(#[case] input: &str, #[case] expected: Dice) {
-        let (remains, draw) = parse_draw(input).unwrap();
+        let (remains, draw) = parse_draw_with(DuplicatePolicy::default())(input).unwrap();
         assert_eq!(draw, expected);
         assert_eq!(remains, "");
     }
@@ -97,4 +185,74 @@ mod tests {
         assert_eq!(game, expected_game);
         assert_eq!(remains, "");
     }
+
+    #[rstest]
+    #[case(Game { number: 1, draws: vec![Dice { red: 4, blue: 3, green: 0}, Dice { red: 1, green: 2, blue: 6}, Dice {red: 0, green: 2, blue: 0}]}, "Game 1: 4 red, 3 blue; 1 red, 2 green, 6 blue; 2 green")]
+    fn test_display_game(#[case] game: Game, #[case] expected: &str) {
+        assert_eq!(game.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 red, 2 blue, 3 red; 1 green", vec![Draw { quantity_colors: vec![(3, Color::Red), (2, Color::Blue), (3, Color::Red)] }, Draw { quantity_colors: vec![(1, Color::Green)] }])]
+    fn test_parse_raw_game(#[case] input: &str, #[case] expected_draws: Vec<Draw>) {
+        let (remains, game) = parse_raw_game(input).unwrap();
+        assert_eq!(game.draws, expected_draws);
+        assert_eq!(game.to_string(), input);
+        assert_eq!(remains, "");
+    }
+
+    #[rstest]
+    #[case(DuplicatePolicy::KeepFirst, Dice { red: 3, green: 0, blue: 2 })]
+    #[case(DuplicatePolicy::Sum, Dice { red: 8, green: 0, blue: 2 })]
+    fn test_parse_game_with(#[case] policy: DuplicatePolicy, #[case] expected_draw: Dice) {
+        let (remains, game) = parse_game_with(policy)("Game 7: 3 red, 2 blue, 5 red").unwrap();
+        assert_eq!(game.draws, vec![expected_draw]);
+        assert_eq!(remains, "");
+    }
+
+    #[rstest]
+    fn test_parse_game_with_reject() {
+        let err = parse_game_with(DuplicatePolicy::Reject)("Game 7: 1 green; 3 red, 2 blue, 5 red")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            nom::Err::Failure(ParseGameError {
+                input: " 3 red, 2 blue, 5 red",
+                kind: ErrorKind::Verify,
+                message: Some(
+                    "red is listed more than once in draw \"3 red, 2 blue, 5 red\".".to_string()
+                ),
+            })
+        );
+        let nom::Err::Failure(err) = err else {
+            unreachable!()
+        };
+        assert_eq!(
+            err.to_string(),
+            "red is listed more than once in draw \"3 red, 2 blue, 5 red\"."
+        );
+    }
+
+    fn arb_dice() -> impl Strategy<Value = Dice> {
+        (0..100u32, 0..100u32, 0..100u32)
+            .prop_filter("a draw shows at least one die", |(red, green, blue)| {
+                red + green + blue > 0
+            })
+            .prop_map(|(red, green, blue)| Dice { red, green, blue })
+    }
+
+    fn arb_game() -> impl Strategy<Value = Game> {
+        (any::<u32>(), prop::collection::vec(arb_dice(), 1..10))
+            .prop_map(|(number, draws)| Game { number, draws })
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(game in arb_game()) {
+            let input = game.to_string();
+            let (remains, parsed) = parse_game(&input).unwrap();
+            prop_assert_eq!(parsed, game);
+            prop_assert_eq!(remains, "");
+        }
+    }
 }
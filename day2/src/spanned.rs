@@ -0,0 +1,315 @@
+use crate::game::{Color, Dice, Draw, RawGame};
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till, take_while},
+    character::complete::{digit1, line_ending, space0},
+    combinator::{eof, map_res, opt},
+    error::{Error, ErrorKind},
+    multi::many1,
+    Finish, IResult,
+};
+use nom_locate::LocatedSpan;
+use std::fmt;
+
+pub type Input<'a> = LocatedSpan<&'a str>;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Span {
+    pub offset: usize,
+    pub line: u32,
+    pub column: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SpannedGame {
+    pub number: Spanned<u32>,
+    pub draws: Vec<SpannedDraw>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SpannedDraw {
+    pub quantity_colors: Vec<Spanned<(u32, Color)>>,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Violation {
+    pub quantity: u32,
+    pub color: Color,
+    pub limit: u32,
+    pub span: Span,
+}
+
+impl Span {
+    fn between(start: Input, end: Input) -> Self {
+        Self {
+            offset: start.location_offset(),
+            line: start.location_line(),
+            column: start.get_utf8_column(),
+            len: end.location_offset() - start.location_offset(),
+        }
+    }
+}
+
+impl SpannedGame {
+    pub fn to_raw_game(&self) -> RawGame {
+        RawGame {
+            number: self.number.value,
+            draws: self.draws.iter().map(SpannedDraw::to_draw).collect(),
+        }
+    }
+
+    pub fn violations(&self, bag: &Dice) -> Vec<Violation> {
+        self.draws
+            .iter()
+            .flat_map(|draw| draw.quantity_colors.iter())
+            .filter(
+                |Spanned {
+                     value: (quantity, color),
+                     ..
+                 }| *quantity > bag.count(*color),
+            )
+            .map(
+                |Spanned {
+                     value: (quantity, color),
+                     span,
+                 }| Violation {
+                    quantity: *quantity,
+                    color: *color,
+                    limit: bag.count(*color),
+                    span: *span,
+                },
+            )
+            .collect()
+    }
+}
+
+impl SpannedDraw {
+    pub fn to_draw(&self) -> Draw {
+        Draw {
+            quantity_colors: self
+                .quantity_colors
+                .iter()
+                .map(|quantity_color| quantity_color.value)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {} {} exceeds limit {}",
+            self.span.line, self.span.column, self.quantity, self.color, self.limit
+        )
+    }
+}
+
+fn game_number(input: Input) -> IResult<Input, Spanned<u32>> {
+    let (input, _) = tag("Game ")(input)?;
+    let start = input;
+    let (input, number) = map_res(take_till(|c| c == ':'), |number: Input| {
+        number.fragment().parse::<u32>()
+    })(input)?;
+    let span = Span::between(start, input);
+    Ok((
+        input,
+        Spanned {
+            value: number,
+            span,
+        },
+    ))
+}
+
+fn quantity_color(input: Input) -> IResult<Input, Spanned<(u32, Color)>> {
+    let (input, _) = space0(input)?;
+    let start = input;
+    let (input, quantity) =
+        map_res(digit1, |quantity: Input| quantity.fragment().parse::<u32>())(input)?;
+    let (input, _) = space0(input)?;
+    let (input, color) = map_res(take_while(char::is_alphabetic), |color: Input| {
+        Color::parse(color.fragment())
+    })(input)?;
+    let span = Span::between(start, input);
+    let (input, _) = opt(tag(","))(input)?;
+    Ok((
+        input,
+        Spanned {
+            value: (quantity, color),
+            span,
+        },
+    ))
+}
+
+fn parse_draw(input: Input) -> IResult<Input, SpannedDraw> {
+    let (input, _) = space0(input)?;
+    let start = input;
+    let (input, quantity_colors) = many1(quantity_color)(input)?;
+    let span = Span::between(start, input);
+    let (input, _) = opt(tag(";"))(input)?;
+    Ok((
+        input,
+        SpannedDraw {
+            quantity_colors,
+            span,
+        },
+    ))
+}
+
+fn end_of_line(input: Input) -> IResult<Input, ()> {
+    let ending: IResult<Input, Input> = alt((line_ending, eof))(input);
+    match ending {
+        Ok((input, _)) => Ok((input, ())),
+        Err(_) => {
+            let (unexpected, _) = space0(input)?;
+            Err(nom::Err::Error(Error::new(unexpected, ErrorKind::Eof)))
+        }
+    }
+}
+
+pub fn parse_spanned_game(input: Input) -> IResult<Input, SpannedGame> {
+    let start = input;
+    let (input, number) = game_number(input)?;
+    let (input, _) = tag(":")(input)?;
+    let (input, draws) = many1(parse_draw)(input)?;
+    let span = Span::between(start, input);
+    let (input, _) = end_of_line(input)?;
+    Ok((
+        input,
+        SpannedGame {
+            number,
+            draws,
+            span,
+        },
+    ))
+}
+
+pub fn parse_spanned_games(input: &str) -> Result<Vec<SpannedGame>> {
+    let mut input = Input::new(input);
+    let mut games = vec![];
+    while !input.fragment().is_empty() {
+        let (remains, game) = parse_spanned_game(input).finish().map_err(|err| {
+            anyhow!(
+                "{}:{}: cannot parse game",
+                err.input.location_line(),
+                err.input.get_utf8_column()
+            )
+        })?;
+        games.push(game);
+        input = remains;
+    }
+    Ok(games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_raw_game;
+    use rstest::*;
+
+    const SAMPLE: &str = include_str!("../puzzle_inputs/part1_sample.txt");
+
+    #[rstest]
+    fn test_spanned_games_match_raw_games() {
+        let games = parse_spanned_games(SAMPLE).unwrap();
+        let raw_games: Vec<RawGame> = SAMPLE
+            .lines()
+            .map(|line| parse_raw_game(line).unwrap().1)
+            .collect();
+        assert_eq!(
+            games
+                .iter()
+                .map(SpannedGame::to_raw_game)
+                .collect::<Vec<_>>(),
+            raw_games
+        );
+    }
+
+    #[rstest]
+    fn test_crlf_line_endings() {
+        let games = parse_spanned_games("Game 1: 3 blue\r\nGame 2: 4 red\r\n").unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].span.len, "Game 1: 3 blue".len());
+        assert_eq!(games[1].number.span.line, 2);
+        assert_eq!(games[1].to_raw_game().to_string(), "Game 2: 4 red");
+    }
+
+    #[rstest]
+    fn test_spans() {
+        let games = parse_spanned_games(SAMPLE).unwrap();
+        let game = &games[2];
+        assert_eq!(
+            game.number.span,
+            Span {
+                offset: 125,
+                line: 3,
+                column: 6,
+                len: 1
+            }
+        );
+        assert_eq!(
+            &SAMPLE[game.span.offset..][..game.span.len],
+            SAMPLE.lines().nth(2).unwrap()
+        );
+        let draw = &game.draws[1];
+        assert_eq!(
+            &SAMPLE[draw.span.offset..][..draw.span.len],
+            "5 blue, 4 red, 13 green"
+        );
+    }
+
+    #[rstest]
+    fn test_violations() {
+        let bag = Dice {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let violations: Vec<String> = parse_spanned_games(SAMPLE)
+            .unwrap()
+            .iter()
+            .flat_map(|game| game.violations(&bag))
+            .map(|violation| violation.to_string())
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "3:26: 20 red exceeds limit 12",
+                "4:58: 15 blue exceeds limit 14",
+                "4:67: 14 red exceeds limit 12",
+            ]
+        );
+        let offsets: Vec<usize> = parse_spanned_games(SAMPLE)
+            .unwrap()
+            .iter()
+            .flat_map(|game| game.violations(&bag))
+            .map(|violation| violation.span.offset)
+            .collect();
+        assert_eq!(offsets, vec![145, 250, 259]);
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue\nGame 2: 4 purple\n", "2:11: cannot parse game")]
+    #[case("Game 1: 3 blue\nGame 2: 4 red; ; 1 blue\n", "2:16: cannot parse game")]
+    #[case("Game 1: 3 blue\nGame 2: 4 red!\n", "2:14: cannot parse game")]
+    #[case("Game 1: 3 blue\n\nGame 2: 4 red\n", "2:1: cannot parse game")]
+    #[case("Game 1: 3 blue\nGame x: 4 red\n", "2:6: cannot parse game")]
+    #[case(
+        "Game 1: 3 blue\n20 red, 2 green\nGame 2: 1 red\n",
+        "2:1: cannot parse game"
+    )]
+    fn test_parse_errors(#[case] input: &str, #[case] expected: &str) {
+        let err = parse_spanned_games(input).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+}
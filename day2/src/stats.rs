@@ -0,0 +1,179 @@
+use crate::game::{Color, Dice, Game};
+use itertools::Itertools;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct GameStats {
+    pub games: usize,
+    pub total_draws: usize,
+    pub red: Distribution,
+    pub green: Distribution,
+    pub blue: Distribution,
+    pub power: Distribution,
+    pub most_constraining_color: Option<Color>,
+    pub possible_games: Vec<u32>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Distribution {
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    pub median: f64,
+}
+
+impl Distribution {
+    pub fn new(values: impl IntoIterator<Item = u32>) -> Self {
+        let values: Vec<u32> = values.into_iter().sorted().collect();
+        let (Some(&min), Some(&max)) = (values.first(), values.last()) else {
+            return Self::default();
+        };
+        let mean = values.iter().map(|&value| f64::from(value)).sum::<f64>() / values.len() as f64;
+        let middle = values.len() / 2;
+        let median = if values.len().is_multiple_of(2) {
+            (f64::from(values[middle - 1]) + f64::from(values[middle])) / 2.0
+        } else {
+            f64::from(values[middle])
+        };
+        Self {
+            min,
+            max,
+            mean,
+            median,
+        }
+    }
+}
+
+impl GameStats {
+    pub fn new(games: &[Game], bag: &Dice) -> Self {
+        let draws = || games.iter().flat_map(|game| game.draws.iter());
+        let most_shown: Vec<Dice> = games.iter().map(Game::most_dice_shown).collect();
+        let most_constraining_color = [Color::Red, Color::Green, Color::Blue]
+            .into_iter()
+            .map(|color| {
+                let ruled_out = most_shown
+                    .iter()
+                    .filter(|dice| dice.count(color) > bag.count(color))
+                    .count();
+                (color, ruled_out)
+            })
+            .filter(|&(_, ruled_out)| ruled_out > 0)
+            .rev()
+            .max_by_key(|&(_, ruled_out)| ruled_out)
+            .map(|(color, _)| color);
+        Self {
+            games: games.len(),
+            total_draws: draws().count(),
+            red: Distribution::new(draws().map(|draw| draw.red)),
+            green: Distribution::new(draws().map(|draw| draw.green)),
+            blue: Distribution::new(draws().map(|draw| draw.blue)),
+            power: Distribution::new(most_shown.iter().map(Dice::power)),
+            most_constraining_color,
+            possible_games: games
+                .iter()
+                .filter(|game| game.is_possible(bag))
+                .map(|game| game.number)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for GameStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<26}{}", "games", self.games)?;
+        writeln!(f, "{:<26}{}", "draws", self.total_draws)?;
+        writeln!(
+            f,
+            "{:<26}{:>8}{:>8}{:>10}{:>10}",
+            "", "min", "max", "mean", "median"
+        )?;
+        for (name, distribution) in [
+            ("red per draw", &self.red),
+            ("green per draw", &self.green),
+            ("blue per draw", &self.blue),
+            ("power per game", &self.power),
+        ] {
+            writeln!(
+                f,
+                "{:<26}{:>8}{:>8}{:>10.2}{:>10.2}",
+                name, distribution.min, distribution.max, distribution.mean, distribution.median
+            )?;
+        }
+        let most_constraining_color = match &self.most_constraining_color {
+            Some(color) => color.to_string(),
+            None => "none".to_string(),
+        };
+        writeln!(
+            f,
+            "{:<26}{}",
+            "most constraining color", most_constraining_color
+        )?;
+        write!(
+            f,
+            "{:<26}{}",
+            "possible games",
+            self.possible_games.iter().join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_game;
+    use rstest::*;
+
+    #[fixture]
+    fn sample_games() -> Vec<Game> {
+        include_str!("../puzzle_inputs/part1_sample.txt")
+            .lines()
+            .map(|line| parse_game(line).unwrap().1)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec![], Distribution::default())]
+    #[case(vec![3, 1, 2], Distribution { min: 1, max: 3, mean: 2.0, median: 2.0 })]
+    #[case(vec![4, 1, 2, 1], Distribution { min: 1, max: 4, mean: 2.0, median: 1.5 })]
+    fn test_distribution(#[case] values: Vec<u32>, #[case] expected: Distribution) {
+        assert_eq!(Distribution::new(values), expected);
+    }
+
+    #[rstest]
+    fn test_game_stats(sample_games: Vec<Game>) {
+        let bag = Dice {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let stats = GameStats::new(&sample_games, &bag);
+        assert_eq!(stats.games, 5);
+        assert_eq!(stats.total_draws, 14);
+        assert_eq!(stats.red.median, 2.0);
+        assert!((stats.red.mean - 61.0 / 14.0).abs() < 1e-12);
+        assert_eq!(
+            stats.power,
+            Distribution {
+                min: 12,
+                max: 1560,
+                mean: 457.2,
+                median: 48.0
+            }
+        );
+        assert_eq!(stats.most_constraining_color, Some(Color::Red));
+        assert_eq!(stats.possible_games, vec![1, 2, 5]);
+    }
+
+    #[rstest]
+    fn test_game_stats_without_impossible_games(sample_games: Vec<Game>) {
+        let bag = Dice {
+            red: 20,
+            green: 20,
+            blue: 20,
+        };
+        let stats = GameStats::new(&sample_games, &bag);
+        assert_eq!(stats.most_constraining_color, None);
+        assert_eq!(stats.possible_games, vec![1, 2, 3, 4, 5]);
+    }
+}
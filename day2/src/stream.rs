@@ -0,0 +1,252 @@
+use crate::bytes::{game_number, parse_draw};
+use crate::game::{Dice, Game};
+use anyhow::{anyhow, Result};
+use std::io::{self, BufRead};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct GameSummary {
+    pub number: u32,
+    pub most_dice_shown: Dice,
+    pub draws: usize,
+}
+
+impl GameSummary {
+    pub fn power(&self) -> u32 {
+        self.most_dice_shown.power()
+    }
+
+    pub fn is_possible(&self, bag: &Dice) -> bool {
+        bag.can_hold(&self.most_dice_shown)
+    }
+}
+
+impl From<&Game> for GameSummary {
+    fn from(game: &Game) -> Self {
+        Self {
+            number: game.number,
+            most_dice_shown: game.most_dice_shown(),
+            draws: game.draws.len(),
+        }
+    }
+}
+
+pub struct Summaries<R> {
+    reader: R,
+    segment: Vec<u8>,
+    line: usize,
+    at_line_start: bool,
+}
+
+pub fn summaries<R: BufRead>(reader: R) -> Summaries<R> {
+    Summaries {
+        reader,
+        segment: vec![],
+        line: 0,
+        at_line_start: true,
+    }
+}
+
+fn parse_whole_draw(segment: &[u8]) -> Option<Dice> {
+    match parse_draw(segment) {
+        Ok((b"", dice)) => Some(dice),
+        _ => None,
+    }
+}
+
+impl<R: BufRead> Summaries<R> {
+    fn read_segment(&mut self) -> io::Result<Option<u8>> {
+        self.segment.clear();
+        let terminator = loop {
+            let available = self.reader.fill_buf()?;
+            if available.is_empty() {
+                break None;
+            }
+            match available.iter().position(|&b| b == b';' || b == b'\n') {
+                Some(end) => {
+                    let terminator = available[end];
+                    self.segment.extend_from_slice(&available[..end]);
+                    self.reader.consume(end + 1);
+                    break Some(terminator);
+                }
+                None => {
+                    let end = available.len();
+                    self.segment.extend_from_slice(available);
+                    self.reader.consume(end);
+                }
+            }
+        };
+        self.at_line_start = terminator != Some(b';');
+        if self.at_line_start && self.segment.last() == Some(&b'\r') {
+            self.segment.pop();
+        }
+        Ok(terminator)
+    }
+
+    fn skip_line(&mut self) -> io::Result<()> {
+        loop {
+            let available = self.reader.fill_buf()?;
+            if available.is_empty() {
+                return Ok(());
+            }
+            match available.iter().position(|&b| b == b'\n') {
+                Some(end) => {
+                    self.reader.consume(end + 1);
+                    return Ok(());
+                }
+                None => {
+                    let end = available.len();
+                    self.reader.consume(end);
+                }
+            }
+        }
+    }
+
+    fn parse_segment<T>(&self, parse: impl FnOnce(&[u8]) -> Option<T>) -> Result<T> {
+        parse(&self.segment).ok_or_else(|| {
+            anyhow!(
+                "Cannot parse {:?} on line {} as part of a Game.",
+                String::from_utf8_lossy(&self.segment),
+                self.line
+            )
+        })
+    }
+
+    fn next_game(&mut self) -> Result<Option<GameSummary>> {
+        let mut terminator = self.read_segment()?;
+        if terminator.is_none() && self.segment.is_empty() {
+            return Ok(None);
+        }
+        self.line += 1;
+        let (number, mut most_dice_shown) = self.parse_segment(|segment| {
+            let (rest, number) = game_number(segment).ok()?;
+            Some((number, parse_whole_draw(rest.strip_prefix(b":")?)?))
+        })?;
+        let mut draws = 1;
+        while terminator == Some(b';') {
+            terminator = self.read_segment()?;
+            if self.at_line_start && self.segment.is_empty() {
+                break;
+            }
+            most_dice_shown = most_dice_shown.max(&self.parse_segment(parse_whole_draw)?);
+            draws += 1;
+        }
+        Ok(Some(GameSummary {
+            number,
+            most_dice_shown,
+            draws,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for Summaries<R> {
+    type Item = Result<GameSummary>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_game() {
+            Ok(summary) => summary.map(Ok),
+            Err(err) => {
+                if !self.at_line_start {
+                    if let Err(skip_err) = self.skip_line() {
+                        return Some(Err(skip_err.into()));
+                    }
+                    self.at_line_start = true;
+                }
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::summarize_game_bytes;
+    use crate::generate::{generate, GeneratorConfig};
+    use crate::parse::parse_game;
+    use itertools::Itertools;
+    use rstest::*;
+    use std::io::{BufReader, Cursor};
+
+    const SAMPLE: &str = include_str!("../puzzle_inputs/part1_sample.txt");
+
+    #[rstest]
+    fn test_summaries_match_games() {
+        let expected: Vec<GameSummary> = SAMPLE
+            .lines()
+            .map(|line| GameSummary::from(&parse_game(line).unwrap().1))
+            .collect();
+        let streamed: Vec<GameSummary> = summaries(Cursor::new(SAMPLE)).try_collect().unwrap();
+        assert_eq!(streamed, expected);
+    }
+
+    #[rstest]
+    #[case(0, 0.0)]
+    #[case(1, 0.3)]
+    fn test_summaries_match_line_parser(#[case] seed: u64, #[case] malformed_rate: f64) {
+        let input = generate(&GeneratorConfig {
+            games: 300,
+            seed,
+            malformed_rate,
+            ..Default::default()
+        })
+        .unwrap();
+        let streamed: Vec<Option<GameSummary>> = summaries(Cursor::new(&input))
+            .map(|summary| summary.ok())
+            .collect();
+        let expected: Vec<Option<GameSummary>> = input
+            .lines()
+            .map(|line| match summarize_game_bytes(line.as_bytes()) {
+                Ok((b"", summary)) => Some(summary),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(streamed, expected);
+    }
+
+    #[rstest]
+    fn test_summaries_memory_is_independent_of_draw_count() {
+        let draws = 100_000;
+        let input = format!("Game 1: {}\n", vec!["1 red, 2 blue"; draws].join("; "));
+        let mut summaries = summaries(BufReader::with_capacity(64, input.as_bytes()));
+        let summary = summaries.next().unwrap().unwrap();
+        assert_eq!(summary.draws, draws);
+        assert!(summaries.segment.capacity() <= 64);
+        assert!(summaries.next().is_none());
+    }
+
+    #[rstest]
+    fn test_summaries_answers() {
+        let bag = Dice {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let (possible_sum, sum_of_powers) = summaries(Cursor::new(SAMPLE))
+            .process_results(|summaries| {
+                summaries.fold((0, 0), |(possible_sum, sum_of_powers), summary| {
+                    let possible = if summary.is_possible(&bag) {
+                        summary.number
+                    } else {
+                        0
+                    };
+                    (possible_sum + possible, sum_of_powers + summary.power())
+                })
+            })
+            .unwrap();
+        assert_eq!(possible_sum, 8);
+        assert_eq!(sum_of_powers, 2286);
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue\r\nGame 2: 1 red\r\n", vec![true, true])]
+    #[case("Game 1: 3 blue\nGame 2: 1 purple\nGame 3: 1 red", vec![true, false, true])]
+    #[case("Game 1: 3 blue; ; 1 red\n", vec![false])]
+    #[case("Game 1: 3 blue;\nGame 2: 1 red; 2 red\n\nGame 3: 1 red", vec![true, true, false, true])]
+    #[case("Game 1: 3 blue;;\nGame 2 1 red\nGame 3:\nGame 4: 1 red ", vec![false, false, false, false])]
+    fn test_summaries_lines(#[case] input: &str, #[case] expected_ok: Vec<bool>) {
+        let ok: Vec<bool> = summaries(Cursor::new(input))
+            .map(|summary| summary.is_ok())
+            .collect();
+        assert_eq!(ok, expected_ok);
+    }
+}
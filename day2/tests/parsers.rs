@@ -0,0 +1,66 @@
+use day2::{
+    bytes::parse_game_bytes,
+    generate::{generate, GeneratorConfig},
+    parse::parse_game,
+};
+use rstest::*;
+
+fn assert_same_parse(line: &str) {
+    match (parse_game(line), parse_game_bytes(line.as_bytes())) {
+        (Ok((remains, game)), Ok((byte_remains, byte_game))) => {
+            assert_eq!(game, byte_game, "{line}");
+            assert_eq!(remains.as_bytes(), byte_remains, "{line}");
+        }
+        (Err(_), Err(_)) => {}
+        (str_result, byte_result) => panic!(
+            "parsers disagree on {line:?}: {:?} vs {:?}",
+            str_result.is_ok(),
+            byte_result.is_ok()
+        ),
+    }
+}
+
+#[rstest]
+#[case(include_str!("../puzzle_inputs/part1_sample.txt"))]
+#[case(include_str!("../puzzle_inputs/part1.txt"))]
+fn puzzle_inputs(#[case] input: &str) {
+    input.lines().for_each(assert_same_parse);
+}
+
+#[rstest]
+#[case(0, 0.0)]
+#[case(1, 0.0)]
+#[case(2, 0.3)]
+#[case(3, 1.0)]
+fn generated_inputs(#[case] seed: u64, #[case] malformed_rate: f64) {
+    let input = generate(&GeneratorConfig {
+        games: 500,
+        seed,
+        malformed_rate,
+        ..Default::default()
+    })
+    .unwrap();
+    input.lines().for_each(assert_same_parse);
+}
+
+#[rstest]
+#[case("Game 1: 3 red, 2 blue, 5 red; 1 green")]
+#[case("Game 1: 3 blue,4 red;1 green")]
+#[case("Game 1:3 blue\n")]
+#[case("Game 1: 3 blue; ; 4 red")]
+#[case("Game 1: 99999999999 red")]
+#[case("Game 99999999999: 1 red")]
+#[case("Game +1: 1 red")]
+#[case("Game : 1 red")]
+#[case("Game 1: 1 purple")]
+#[case("Game 1: 3 redé")]
+#[case("Game 1: 3 blue, 3 redé")]
+#[case("Game 1: 3 red€")]
+#[case("Game 1: 3 rouge\u{301}")]
+#[case("Game 1: 3 red\u{fffd}")]
+#[case("Game 1: red")]
+#[case("Game 1")]
+#[case("")]
+fn edge_cases(#[case] line: &str) {
+    assert_same_parse(line);
+}
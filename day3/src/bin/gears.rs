@@ -0,0 +1,22 @@
+use anyhow::{bail, Result};
+use day3::{
+    export::{write_csv, write_json_lines},
+    Schematic,
+};
+use std::{env, fs::read_to_string, io::stdout, str::FromStr};
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let format = args.next().unwrap_or_else(|| "json".to_string());
+    let path = args
+        .next()
+        .unwrap_or_else(|| "puzzle_inputs/input.txt".to_string());
+    let input = read_to_string(path)?;
+    let schematic = Schematic::from_str(&input)?;
+    let gears: Vec<_> = schematic.gears().collect::<Result<_, _>>()?;
+    match format.as_str() {
+        "json" => write_json_lines(&gears, stdout().lock()),
+        "csv" => write_csv(&gears, stdout().lock()),
+        _ => bail!("Unknown export format {format}, expected json or csv."),
+    }
+}
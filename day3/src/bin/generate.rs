@@ -0,0 +1,25 @@
+use anyhow::Result;
+use day3::generate::{generate, GeneratorConfig};
+use std::env;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut config = GeneratorConfig::default();
+    if let Some(width) = args.first() {
+        config.width = width.parse()?;
+    }
+    if let Some(height) = args.get(1) {
+        config.height = height.parse()?;
+    }
+    if let Some(seed) = args.get(2) {
+        config.seed = seed.parse()?;
+    }
+    if let Some(planted_gears) = args.get(3) {
+        config.planted_gears = planted_gears.parse()?;
+    }
+    let generated = generate(&config)?;
+    print!("{}", generated.text);
+    eprintln!("{}", generated.part_number_sum);
+    eprintln!("{}", generated.gear_ratio_sum);
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use day3::Schematic;
+use std::{env, fs::read_to_string, str::FromStr};
+
+fn main() -> Result<()> {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "puzzle_inputs/input.txt".to_string());
+    let input = read_to_string(path)?;
+    let schematic = Schematic::from_str(&input)?;
+    print!("{}", schematic.adjacency_graph().to_dot());
+    Ok(())
+}
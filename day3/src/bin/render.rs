@@ -0,0 +1,23 @@
+use anyhow::Result;
+use day3::{
+    render::{render_ansi, render_html},
+    Schematic,
+};
+use std::{env, fs::read_to_string, str::FromStr};
+
+fn main() -> Result<()> {
+    let (flags, paths): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|arg| arg.starts_with("--"));
+    let html = flags.iter().any(|flag| flag == "--html");
+    let path = paths
+        .first()
+        .map_or("puzzle_inputs/input.txt", String::as_str);
+    let input = read_to_string(path)?;
+    let schematic = Schematic::from_str(&input)?;
+    if html {
+        print!("{}", render_html(&schematic));
+    } else {
+        print!("{}", render_ansi(&schematic));
+    }
+    Ok(())
+}
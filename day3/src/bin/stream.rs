@@ -0,0 +1,25 @@
+use anyhow::Result;
+use day3::{
+    error::OverflowError,
+    stream::{solve_stream, StreamEvent},
+};
+use std::{env, fs::File, io::BufReader};
+
+fn main() -> Result<()> {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "puzzle_inputs/input.txt".to_string());
+    let input = BufReader::new(File::open(path)?);
+    let mut part_numbers_sum: i64 = 0;
+    let mut gear_ratios_sum: i64 = 0;
+    for event in solve_stream(input, &Default::default(), &Default::default()) {
+        let (sum, value) = match event? {
+            StreamEvent::PartNumber(number) => (&mut part_numbers_sum, number.value),
+            StreamEvent::Gear { value, .. } => (&mut gear_ratios_sum, value),
+        };
+        *sum = sum.checked_add(value).ok_or(OverflowError)?;
+    }
+    println!("{part_numbers_sum}");
+    println!("{gear_ratios_sum}");
+    Ok(())
+}
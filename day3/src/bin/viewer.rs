@@ -0,0 +1,138 @@
+use anyhow::Result;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use day3::{
+    grid::{Grid, Point},
+    render::{classify, CellStyle},
+    Schematic,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+};
+use std::{env, fs::read_to_string, io::stdout, str::FromStr};
+
+struct Viewer {
+    schematic: Schematic,
+    styles: Grid<CellStyle>,
+    cursor: Point,
+}
+
+impl Viewer {
+    fn move_cursor(&mut self, rows: isize, cols: isize) {
+        let row = self.cursor.row.saturating_add_signed(rows);
+        let col = self.cursor.col.saturating_add_signed(cols);
+        self.cursor = Point::new(
+            row.min(self.schematic.height().saturating_sub(1)),
+            col.min(self.schematic.width().saturating_sub(1)),
+        );
+    }
+
+    fn color(style: CellStyle) -> Style {
+        match style {
+            CellStyle::Blank => Style::default().fg(Color::DarkGray),
+            CellStyle::PartNumber => Style::default().fg(Color::Green),
+            CellStyle::IsolatedNumber => Style::default().fg(Color::Red),
+            CellStyle::Symbol => Style::default().fg(Color::Yellow),
+            CellStyle::Gear => Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(40)])
+            .split(frame.size());
+        let visible_rows = areas[0].height.saturating_sub(2) as usize;
+        let visible_cols = areas[0].width.saturating_sub(2) as usize;
+        let top = self
+            .cursor
+            .row
+            .saturating_sub(visible_rows.saturating_sub(1));
+        let left = self
+            .cursor
+            .col
+            .saturating_sub(visible_cols.saturating_sub(1));
+        let lines: Vec<Line> = (top..self.schematic.height().min(top + visible_rows))
+            .map(|row| {
+                let cells = self.schematic.grid.row(row).expect("row is visible");
+                let styles = self.styles.row(row).expect("row is visible");
+                cells
+                    .iter()
+                    .zip(styles)
+                    .enumerate()
+                    .skip(left)
+                    .take(visible_cols)
+                    .map(|(col, (cell, style))| {
+                        let mut style = Self::color(*style);
+                        if Point::new(row, col) == self.cursor {
+                            style = style.add_modifier(Modifier::REVERSED);
+                        }
+                        Span::styled(cell.to_string(), style)
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("schematic")),
+            areas[0],
+        );
+        let info = match self.schematic.inspect(self.cursor) {
+            Some(selection) => format!("{}:{}\n{selection}", self.cursor.row, self.cursor.col),
+            None => String::new(),
+        };
+        frame.render_widget(
+            Paragraph::new(info).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("cell (arrows/hjkl, q to quit)"),
+            ),
+            areas[1],
+        );
+    }
+}
+
+fn run(viewer: &mut Viewer) -> Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    loop {
+        terminal.draw(|frame| viewer.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => viewer.move_cursor(-1, 0),
+                KeyCode::Down | KeyCode::Char('j') => viewer.move_cursor(1, 0),
+                KeyCode::Left | KeyCode::Char('h') => viewer.move_cursor(0, -1),
+                KeyCode::Right | KeyCode::Char('l') => viewer.move_cursor(0, 1),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "puzzle_inputs/input.txt".to_string());
+    let input = read_to_string(path)?;
+    let schematic = Schematic::from_str(&input)?;
+    let mut viewer = Viewer {
+        styles: classify(&schematic),
+        schematic,
+        cursor: Point::new(0, 0),
+    };
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    let result = run(&mut viewer);
+    execute!(stdout(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
@@ -0,0 +1,74 @@
+use crate::grid::Neighborhood;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SchematicConfig {
+    pub blanks: Vec<char>,
+    pub symbols: Symbols,
+    pub gears: Vec<char>,
+    pub signed_numbers: bool,
+    pub neighborhood: Neighborhood,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Symbols {
+    Any,
+    Only(Vec<char>),
+}
+
+impl Default for SchematicConfig {
+    fn default() -> Self {
+        Self {
+            blanks: vec!['.'],
+            symbols: Symbols::Any,
+            gears: vec!['*'],
+            signed_numbers: false,
+            neighborhood: Neighborhood::Moore,
+        }
+    }
+}
+
+impl SchematicConfig {
+    pub fn is_blank(&self, c: char) -> bool {
+        self.blanks.contains(&c)
+    }
+
+    pub fn is_symbol(&self, c: char) -> bool {
+        if c.is_ascii_digit() || self.is_blank(c) {
+            return false;
+        }
+        match &self.symbols {
+            Symbols::Any => !c.is_whitespace(),
+            Symbols::Only(symbols) => symbols.contains(&c),
+        }
+    }
+
+    pub fn is_gear(&self, c: char) -> bool {
+        self.is_symbol(c) && self.gears.contains(&c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(SchematicConfig::default(), '*', true, true)]
+    #[case(SchematicConfig::default(), '#', true, false)]
+    #[case(SchematicConfig::default(), '.', false, false)]
+    #[case(SchematicConfig::default(), '7', false, false)]
+    #[case(SchematicConfig::default(), ' ', false, false)]
+    #[case(SchematicConfig::default(), '\r', false, false)]
+    #[case(SchematicConfig { symbols: Symbols::Only(vec!['#', '*']), ..Default::default() }, '$', false, false)]
+    #[case(SchematicConfig { symbols: Symbols::Only(vec!['#', '*']), gears: vec!['#'], ..Default::default() }, '#', true, true)]
+    #[case(SchematicConfig { blanks: vec!['.', '*'], ..Default::default() }, '*', false, false)]
+    fn test_classes(
+        #[case] config: SchematicConfig,
+        #[case] c: char,
+        #[case] expected_symbol: bool,
+        #[case] expected_gear: bool,
+    ) {
+        assert_eq!(config.is_symbol(c), expected_symbol);
+        assert_eq!(config.is_gear(c), expected_gear);
+    }
+}
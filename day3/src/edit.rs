@@ -0,0 +1,408 @@
+use crate::{
+    check_width,
+    error::{EditError, OverflowError},
+    gear::GearRule,
+    grid::Point,
+    parse_row, Cell, Schematic,
+};
+use std::ops::Range;
+
+#[derive(Debug)]
+pub struct SchematicEditor {
+    schematic: Schematic,
+    rule: GearRule,
+    totals: Totals,
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Totals {
+    part_numbers: i128,
+    gear_values: i128,
+    overflowing_gears: usize,
+}
+
+impl Totals {
+    fn replace(&mut self, old: Totals, new: Totals) {
+        self.part_numbers += new.part_numbers - old.part_numbers;
+        self.gear_values += new.gear_values - old.gear_values;
+        self.overflowing_gears =
+            self.overflowing_gears + new.overflowing_gears - old.overflowing_gears;
+    }
+}
+
+impl Schematic {
+    pub fn set_cell(&mut self, point: Point, c: char) -> Result<(), EditError> {
+        let row = self
+            .grid
+            .row(point.row)
+            .filter(|row| point.col < row.len())
+            .ok_or_else(|| self.out_of_bounds(point))?;
+        let line: String = row
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                if col == point.col {
+                    c.to_string()
+                } else {
+                    cell.to_string()
+                }
+            })
+            .collect();
+        self.replace_row(point.row, &line)
+    }
+
+    pub fn replace_row(&mut self, row: usize, line: &str) -> Result<(), EditError> {
+        if row >= self.height() {
+            return Err(self.out_of_bounds(Point::new(row, 0)));
+        }
+        let mut numbers = vec![];
+        let cells = parse_row(line, row, &self.config, &mut numbers)?;
+        check_width(&cells, Some(self.width()), row)?;
+        let replaced = self.row_numbers(row);
+        let first_index = replaced.start;
+        let delta = numbers.len() as isize - replaced.len() as isize;
+        self.shift_indices(row + 1, delta);
+        self.numbers.splice(replaced, numbers);
+        let slots = self.grid.row_mut(row).expect("row is inside the grid");
+        for (slot, cell) in slots.iter_mut().zip(cells) {
+            *slot = offset_index(cell, first_index);
+        }
+        Ok(())
+    }
+
+    pub fn insert_row(&mut self, row: usize, line: &str) -> Result<(), EditError> {
+        if row > self.height() {
+            return Err(self.out_of_bounds(Point::new(row, 0)));
+        }
+        let mut numbers = vec![];
+        let cells = parse_row(line, row, &self.config, &mut numbers)?;
+        let expected = if self.height() == 0 {
+            None
+        } else {
+            Some(self.width())
+        };
+        check_width(&cells, expected, row)?;
+        let first_index = self.numbers.partition_point(|number| number.row < row);
+        self.shift_indices(row, numbers.len() as isize);
+        for number in &mut self.numbers[first_index..] {
+            number.row += 1;
+        }
+        self.numbers.splice(first_index..first_index, numbers);
+        let cells = cells
+            .into_iter()
+            .map(|cell| offset_index(cell, first_index))
+            .collect();
+        self.grid.insert_row(row, cells);
+        Ok(())
+    }
+
+    pub fn delete_row(&mut self, row: usize) -> Result<(), EditError> {
+        if row >= self.height() {
+            return Err(self.out_of_bounds(Point::new(row, 0)));
+        }
+        let removed = self.row_numbers(row);
+        let first_index = removed.start;
+        let delta = -(removed.len() as isize);
+        self.numbers.drain(removed);
+        for number in &mut self.numbers[first_index..] {
+            number.row -= 1;
+        }
+        self.grid.remove_row(row);
+        self.shift_indices(row, delta);
+        Ok(())
+    }
+
+    fn out_of_bounds(&self, point: Point) -> EditError {
+        EditError::OutOfBounds {
+            point,
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+
+    fn row_numbers(&self, row: usize) -> Range<usize> {
+        let start = self.numbers.partition_point(|number| number.row < row);
+        let end = self.numbers.partition_point(|number| number.row <= row);
+        start..end
+    }
+
+    fn shift_indices(&mut self, from_row: usize, delta: isize) {
+        if delta == 0 {
+            return;
+        }
+        for row in from_row..self.height() {
+            for cell in self.grid.row_mut(row).expect("row is inside the grid") {
+                if let Cell::Number(c, index) = *cell {
+                    *cell = Cell::Number(c, index.wrapping_add_signed(delta));
+                }
+            }
+        }
+    }
+}
+
+fn offset_index(cell: Cell, offset: usize) -> Cell {
+    match cell {
+        Cell::Number(c, index) => Cell::Number(c, index + offset),
+        cell => cell,
+    }
+}
+
+impl SchematicEditor {
+    pub fn new(schematic: Schematic, rule: GearRule) -> Self {
+        let mut editor = Self {
+            schematic,
+            rule,
+            totals: Totals::default(),
+        };
+        editor.totals = editor.band_totals(0, editor.schematic.height());
+        editor
+    }
+
+    pub fn schematic(&self) -> &Schematic {
+        &self.schematic
+    }
+
+    pub fn into_schematic(self) -> Schematic {
+        self.schematic
+    }
+
+    pub fn part_number_sum(&self) -> Result<i64, OverflowError> {
+        i64::try_from(self.totals.part_numbers).map_err(|_| OverflowError)
+    }
+
+    pub fn gear_value_sum(&self) -> Result<i64, OverflowError> {
+        if self.totals.overflowing_gears > 0 {
+            return Err(OverflowError);
+        }
+        i64::try_from(self.totals.gear_values).map_err(|_| OverflowError)
+    }
+
+    pub fn set_cell(&mut self, point: Point, c: char) -> Result<(), EditError> {
+        let (start, end) = self.band(point.row, point.row.saturating_add(1));
+        self.update((start, end), (start, end), |schematic| {
+            schematic.set_cell(point, c)
+        })
+    }
+
+    pub fn insert_row(&mut self, row: usize, line: &str) -> Result<(), EditError> {
+        let before = self.band(row, row);
+        let after = (before.0, before.1.saturating_add(1));
+        self.update(before, after, |schematic| schematic.insert_row(row, line))
+    }
+
+    pub fn delete_row(&mut self, row: usize) -> Result<(), EditError> {
+        let before = self.band(row, row.saturating_add(1));
+        let after = (before.0, before.1 - 1);
+        self.update(before, after, |schematic| schematic.delete_row(row))
+    }
+
+    fn band(&self, start: usize, end: usize) -> (usize, usize) {
+        let radius = self.schematic.config.neighborhood.radius();
+        (start.saturating_sub(radius), end.saturating_add(radius))
+    }
+
+    fn update(
+        &mut self,
+        before: (usize, usize),
+        after: (usize, usize),
+        edit: impl FnOnce(&mut Schematic) -> Result<(), EditError>,
+    ) -> Result<(), EditError> {
+        let old = self.band_totals(before.0, before.1);
+        edit(&mut self.schematic)?;
+        let new = self.band_totals(after.0, after.1);
+        self.totals.replace(old, new);
+        Ok(())
+    }
+
+    fn band_totals(&self, start: usize, end: usize) -> Totals {
+        let schematic = &self.schematic;
+        let end = end.min(schematic.height());
+        let mut totals = Totals::default();
+        if start >= end {
+            return totals;
+        }
+        let first = schematic
+            .numbers
+            .partition_point(|number| number.row < start);
+        let last = schematic.numbers.partition_point(|number| number.row < end);
+        totals.part_numbers = schematic.numbers[first..last]
+            .iter()
+            .filter(|number| schematic.is_part_number(number))
+            .map(|number| i128::from(number.value))
+            .sum();
+        for point in
+            (start..end).flat_map(|row| (0..schematic.width()).map(move |col| Point { row, col }))
+        {
+            match schematic.gear_value(point, &self.rule) {
+                Ok(Some(value)) => totals.gear_values += i128::from(value),
+                Ok(None) => {}
+                Err(OverflowError) => totals.overflowing_gears += 1,
+            }
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::SchematicConfig, grid::Neighborhood};
+    use rstest::*;
+    use std::str::FromStr;
+
+    fn assert_consistent(editor: &SchematicEditor) {
+        let schematic = editor.schematic();
+        let reparsed = Schematic::parse_with(&schematic.to_string(), &schematic.config).unwrap();
+        assert_eq!(schematic.grid, reparsed.grid);
+        assert_eq!(schematic.numbers, reparsed.numbers);
+        let fresh = SchematicEditor::new(reparsed, editor.rule.clone());
+        assert_eq!(editor.part_number_sum(), fresh.part_number_sum());
+        assert_eq!(editor.gear_value_sum(), fresh.gear_value_sum());
+    }
+
+    #[rstest]
+    fn test_totals() {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let editor = SchematicEditor::new(schematic, GearRule::default());
+        assert_eq!(editor.part_number_sum(), Ok(4361));
+        assert_eq!(editor.gear_value_sum(), Ok(467835));
+    }
+
+    #[rstest]
+    #[case(Point::new(1, 3), '.', 4361 - 467 - 35, 467835 - 16345)]
+    #[case(Point::new(0, 8), '*', 4361 + 114, 467835)]
+    #[case(Point::new(0, 3), '9', 4361 - 467 + 4679, 467835 - 16345 + 4679 * 35)]
+    #[case(Point::new(4, 3), '*', 4361, 467835)]
+    fn test_set_cell(
+        #[case] point: Point,
+        #[case] c: char,
+        #[case] expected_parts: i64,
+        #[case] expected_gears: i64,
+    ) {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let mut editor = SchematicEditor::new(schematic, GearRule::default());
+        editor.set_cell(point, c).unwrap();
+        assert_eq!(editor.part_number_sum(), Ok(expected_parts));
+        assert_eq!(editor.gear_value_sum(), Ok(expected_gears));
+        assert_consistent(&editor);
+    }
+
+    #[rstest]
+    fn test_rows() {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let mut editor = SchematicEditor::new(schematic, GearRule::default());
+        editor.insert_row(1, "..........").unwrap();
+        assert_eq!(editor.part_number_sum(), Ok(4361 - 467));
+        assert_eq!(editor.gear_value_sum(), Ok(467835 - 16345));
+        assert_consistent(&editor);
+        editor.delete_row(1).unwrap();
+        assert_eq!(editor.part_number_sum(), Ok(4361));
+        assert_eq!(editor.gear_value_sum(), Ok(467835));
+        assert_consistent(&editor);
+        editor.insert_row(10, "1.........").unwrap();
+        editor.insert_row(0, ".........*").unwrap();
+        assert_eq!(editor.part_number_sum(), Ok(4361));
+        assert_consistent(&editor);
+    }
+
+    #[rstest]
+    fn test_overflowing_gear() {
+        let schematic = Schematic::from_str("9999999999.9999999999").unwrap();
+        let mut editor = SchematicEditor::new(schematic, GearRule::default());
+        editor.set_cell(Point::new(0, 10), '*').unwrap();
+        assert_eq!(editor.gear_value_sum(), Err(OverflowError));
+        assert_eq!(editor.part_number_sum(), Ok(9999999999 * 2));
+        editor.set_cell(Point::new(0, 10), '.').unwrap();
+        assert_eq!(editor.gear_value_sum(), Ok(0));
+        editor.set_cell(Point::new(0, 10), '#').unwrap();
+        assert_eq!(editor.gear_value_sum(), Ok(0));
+        assert_consistent(&editor);
+    }
+
+    #[rstest]
+    fn test_invalid_edits_leave_schematic_unchanged() {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let mut editor = SchematicEditor::new(schematic, GearRule::default());
+        assert!(editor.insert_row(3, "...").is_err());
+        assert!(editor.set_cell(Point::new(2, 2), ' ').is_err());
+        assert!(matches!(
+            editor.insert_row(3, "..."),
+            Err(EditError::Parse(_))
+        ));
+        for (err, point) in [
+            (editor.set_cell(Point::new(10, 0), '*'), Point::new(10, 0)),
+            (editor.set_cell(Point::new(0, 10), '*'), Point::new(0, 10)),
+            (
+                editor.set_cell(Point::new(usize::MAX, 0), '*'),
+                Point::new(usize::MAX, 0),
+            ),
+            (editor.insert_row(11, ".........."), Point::new(11, 0)),
+            (editor.delete_row(10), Point::new(10, 0)),
+            (
+                editor.insert_row(usize::MAX, ".........."),
+                Point::new(usize::MAX, 0),
+            ),
+            (editor.delete_row(usize::MAX), Point::new(usize::MAX, 0)),
+        ] {
+            assert_eq!(
+                err,
+                Err(EditError::OutOfBounds {
+                    point,
+                    width: 10,
+                    height: 10
+                })
+            );
+        }
+        assert_eq!(
+            editor
+                .set_cell(Point::new(0, 10), '*')
+                .unwrap_err()
+                .to_string(),
+            "row 0, column 10 is outside the 10x10 schematic"
+        );
+        assert_eq!(editor.part_number_sum(), Ok(4361));
+        assert_eq!(editor.schematic().height(), 10);
+        assert_consistent(&editor);
+    }
+
+    #[rstest]
+    fn test_random_edits(
+        #[values(
+            Neighborhood::Moore,
+            Neighborhood::VonNeumann,
+            Neighborhood::Chebyshev(2)
+        )]
+        neighborhood: Neighborhood,
+    ) {
+        let config = SchematicConfig {
+            neighborhood,
+            ..Default::default()
+        };
+        let schematic =
+            Schematic::parse_with(include_str!("../puzzle_inputs/sample.txt"), &config).unwrap();
+        let mut editor = SchematicEditor::new(schematic, GearRule::default());
+        let mut state: u64 = 0x2545f4914f6cdd1d;
+        let mut random = |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % bound as u64) as usize
+        };
+        let chars = ['.', '.', '.', '*', '#', '1', '5', '9'];
+        for _ in 0..300 {
+            let height = editor.schematic().height();
+            match random(10) {
+                0 if height > 1 => editor.delete_row(random(height)).unwrap(),
+                1 => {
+                    let line: String = (0..10).map(|_| chars[random(chars.len())]).collect();
+                    editor.insert_row(random(height + 1), &line).unwrap();
+                }
+                _ => {
+                    let point = Point::new(random(height), random(10));
+                    editor.set_cell(point, chars[random(chars.len())]).unwrap();
+                }
+            }
+            assert_consistent(&editor);
+        }
+    }
+}
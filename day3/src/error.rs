@@ -0,0 +1,133 @@
+use crate::grid::Point;
+use std::{fmt, io};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseSchematicError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrorKind {
+    UnexpectedChar(char),
+    EmptyRow,
+    RaggedRow { expected: usize, found: usize },
+    NumberOutOfRange(String),
+}
+
+impl fmt::Display for ParseSchematicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            ParseErrorKind::EmptyRow => write!(f, "empty row"),
+            ParseErrorKind::RaggedRow { expected, found } => {
+                write!(f, "row is {found} cells wide, expected {expected}")
+            }
+            ParseErrorKind::NumberOutOfRange(number) => {
+                write!(f, "{number} does not fit in a 64-bit integer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSchematicError {}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "result does not fit in a 64-bit integer")
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseSchematicError),
+    Overflow(OverflowError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "cannot read schematic: {err}"),
+            StreamError::Parse(err) => err.fmt(f),
+            StreamError::Overflow(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+            StreamError::Overflow(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseSchematicError> for StreamError {
+    fn from(err: ParseSchematicError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+impl From<OverflowError> for StreamError {
+    fn from(err: OverflowError) -> Self {
+        StreamError::Overflow(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EditError {
+    OutOfBounds {
+        point: Point,
+        width: usize,
+        height: usize,
+    },
+    Parse(ParseSchematicError),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EditError::OutOfBounds {
+                point,
+                width,
+                height,
+            } => write!(
+                f,
+                "row {}, column {} is outside the {width}x{height} schematic",
+                point.row, point.col
+            ),
+            EditError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EditError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EditError::OutOfBounds { .. } => None,
+            EditError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseSchematicError> for EditError {
+    fn from(err: ParseSchematicError) -> Self {
+        EditError::Parse(err)
+    }
+}
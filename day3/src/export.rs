@@ -0,0 +1,102 @@
+use crate::gear::GearReport;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GearRecord {
+    pub row: usize,
+    pub col: usize,
+    pub first_value: i64,
+    pub first_row: usize,
+    pub first_col: usize,
+    pub second_value: i64,
+    pub second_row: usize,
+    pub second_col: usize,
+    pub ratio: i64,
+}
+
+impl From<&GearReport> for GearRecord {
+    fn from(gear: &GearReport) -> Self {
+        let [first, second] = gear.parts;
+        GearRecord {
+            row: gear.point.row,
+            col: gear.point.col,
+            first_value: first.value,
+            first_row: first.row,
+            first_col: first.col,
+            second_value: second.value,
+            second_row: second.row,
+            second_col: second.col,
+            ratio: gear.ratio,
+        }
+    }
+}
+
+pub fn write_json_lines<W: Write>(gears: &[GearReport], mut writer: W) -> Result<()> {
+    for gear in gears {
+        serde_json::to_writer(&mut writer, gear)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(gears: &[GearReport], writer: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for gear in gears {
+        writer.serialize(GearRecord::from(gear))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schematic;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[fixture]
+    fn gears() -> Vec<GearReport> {
+        Schematic::from_str(include_str!("../puzzle_inputs/sample.txt"))
+            .unwrap()
+            .gears()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_write_json_lines(gears: Vec<GearReport>) {
+        let mut output = vec![];
+        write_json_lines(&gears, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output.lines().next().unwrap(),
+            concat!(
+                r#"{"point":{"row":1,"col":3},"parts":["#,
+                r#"{"value":467,"row":0,"col":0,"length":3},"#,
+                r#"{"value":35,"row":2,"col":2,"length":2}],"ratio":16345}"#
+            )
+        );
+        let parsed: Vec<GearReport> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, gears);
+    }
+
+    #[rstest]
+    fn test_write_csv(gears: Vec<GearReport>) {
+        let mut output = vec![];
+        write_csv(&gears, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "row,col,first_value,first_row,first_col,second_value,second_row,second_col,ratio\n",
+                "1,3,467,0,0,35,2,2,16345\n",
+                "8,5,755,7,6,598,9,5,451490\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    config::SchematicConfig, error::OverflowError, grid::Point, Cell, Schematic, SchematicNumber,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GearRule {
+    pub symbols: SymbolFilter,
+    pub neighbors: NeighborCount,
+    pub reduction: Reduction,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct GearReport {
+    pub point: Point,
+    pub parts: [SchematicNumber; 2],
+    pub ratio: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SymbolFilter {
+    Gears,
+    Any,
+    Only(Vec<char>),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NeighborCount {
+    Exactly(usize),
+    AtLeast(usize),
+    Between(usize, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Reduction {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        Self {
+            symbols: SymbolFilter::Gears,
+            neighbors: NeighborCount::Exactly(2),
+            reduction: Reduction::Product,
+        }
+    }
+}
+
+impl NeighborCount {
+    pub fn matches(&self, count: usize) -> bool {
+        match *self {
+            NeighborCount::Exactly(expected) => count == expected,
+            NeighborCount::AtLeast(minimum) => count >= minimum,
+            NeighborCount::Between(minimum, maximum) => (minimum..=maximum).contains(&count),
+        }
+    }
+}
+
+impl Reduction {
+    pub fn reduce(&self, values: impl IntoIterator<Item = i64>) -> Result<i64, OverflowError> {
+        let mut values = values.into_iter();
+        match self {
+            Reduction::Product => values
+                .try_fold(1i64, |product, value| product.checked_mul(value))
+                .ok_or(OverflowError),
+            Reduction::Sum => values
+                .try_fold(0i64, |sum, value| sum.checked_add(value))
+                .ok_or(OverflowError),
+            Reduction::Max => Ok(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+impl GearRule {
+    pub fn selects(&self, symbol: char, config: &SchematicConfig) -> bool {
+        match &self.symbols {
+            SymbolFilter::Gears => config.is_gear(symbol),
+            SymbolFilter::Any => true,
+            SymbolFilter::Only(symbols) => symbols.contains(&symbol),
+        }
+    }
+
+    pub fn evaluate(&self, values: &[i64]) -> Result<Option<i64>, OverflowError> {
+        if self.neighbors.matches(values.len()) {
+            self.reduction.reduce(values.iter().copied()).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl Schematic {
+    pub fn gear_value(&self, point: Point, rule: &GearRule) -> Result<Option<i64>, OverflowError> {
+        match self.grid.get(point) {
+            Some(Cell::Symbol(symbol)) if rule.selects(*symbol, &self.config) => {}
+            _ => return Ok(None),
+        }
+        let values: Vec<i64> = self
+            .numbers_adjacent_to(point)
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        rule.evaluate(&values)
+    }
+
+    pub fn gear_values<'a>(
+        &'a self,
+        rule: &'a GearRule,
+    ) -> impl Iterator<Item = Result<(Point, i64), OverflowError>> + 'a {
+        self.symbols().filter_map(|(point, _)| {
+            let value = self.gear_value(point, rule).transpose()?;
+            Some(value.map(|value| (point, value)))
+        })
+    }
+
+    pub fn gears(&self) -> impl Iterator<Item = Result<GearReport, OverflowError>> + '_ {
+        let rule = GearRule::default();
+        self.symbols().filter_map(move |(point, symbol)| {
+            if !rule.selects(symbol, &self.config) {
+                return None;
+            }
+            let numbers = self.numbers_adjacent_to(point);
+            let values: Vec<i64> = numbers.iter().map(|number| number.value).collect();
+            let ratio = match rule.evaluate(&values).transpose()? {
+                Ok(ratio) => ratio,
+                Err(err) => return Some(Err(err)),
+            };
+            let parts = match numbers[..] {
+                [first, second] => [*first, *second],
+                _ => return None,
+            };
+            Some(Ok(GearReport {
+                point,
+                parts,
+                ratio,
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case(GearRule::default(), 467835)]
+    #[case(GearRule { symbols: SymbolFilter::Any, neighbors: NeighborCount::Exactly(1), reduction: Reduction::Sum }, 2506)]
+    #[case(GearRule { symbols: SymbolFilter::Any, neighbors: NeighborCount::AtLeast(1), reduction: Reduction::Max }, 3728)]
+    #[case(GearRule { symbols: SymbolFilter::Only(vec!['*']), neighbors: NeighborCount::Between(1, 2), reduction: Reduction::Sum }, 2472)]
+    #[case(GearRule { symbols: SymbolFilter::Any, neighbors: NeighborCount::AtLeast(3), reduction: Reduction::Product }, 0)]
+    fn test_gear_values(#[case] rule: GearRule, #[case] expected_sum: i64) {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let sum: i64 = schematic
+            .gear_values(&rule)
+            .map(|gear| gear.unwrap().1)
+            .sum();
+        assert_eq!(sum, expected_sum);
+    }
+
+    #[rstest]
+    #[case(Reduction::Product, vec![i64::MAX, 2], Err(OverflowError))]
+    #[case(Reduction::Sum, vec![i64::MAX, 1], Err(OverflowError))]
+    #[case(Reduction::Max, vec![i64::MAX, 1], Ok(i64::MAX))]
+    #[case(Reduction::Product, vec![-3_000_000_000, 3_000_000_000], Ok(-9_000_000_000_000_000_000))]
+    fn test_reduce_overflow(
+        #[case] reduction: Reduction,
+        #[case] values: Vec<i64>,
+        #[case] expected: Result<i64, OverflowError>,
+    ) {
+        assert_eq!(reduction.reduce(values), expected);
+    }
+
+    #[rstest]
+    fn test_gears_overflow() {
+        let schematic = Schematic::from_str("9999999999*9999999999").unwrap();
+        assert_eq!(schematic.gear_ratio(Point::new(0, 10)), Err(OverflowError));
+        assert_eq!(schematic.gears().next(), Some(Err(OverflowError)));
+    }
+
+    #[rstest]
+    fn test_gears() {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let number = |value, row, col, length| SchematicNumber {
+            value,
+            row,
+            col,
+            length,
+        };
+        let gears: Vec<GearReport> = schematic.gears().try_collect().unwrap();
+        assert_eq!(
+            gears,
+            vec![
+                GearReport {
+                    point: Point::new(1, 3),
+                    parts: [number(467, 0, 0, 3), number(35, 2, 2, 2)],
+                    ratio: 16345,
+                },
+                GearReport {
+                    point: Point::new(8, 5),
+                    parts: [number(755, 7, 6, 3), number(598, 9, 5, 3)],
+                    ratio: 451490,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(NeighborCount::Exactly(2), 2, true)]
+    #[case(NeighborCount::Exactly(2), 3, false)]
+    #[case(NeighborCount::AtLeast(2), 3, true)]
+    #[case(NeighborCount::Between(1, 3), 0, false)]
+    #[case(NeighborCount::Between(1, 3), 3, true)]
+    fn test_neighbor_count(
+        #[case] neighbors: NeighborCount,
+        #[case] count: usize,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(neighbors.matches(count), expected);
+    }
+}
@@ -0,0 +1,387 @@
+use crate::grid::Point;
+use anyhow::{anyhow, bail, ensure, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+#[derive(Debug, Clone)]
+pub struct GeneratorConfig {
+    pub width: usize,
+    pub height: usize,
+    pub number_density: f64,
+    pub symbol_density: f64,
+    pub symbols: Vec<char>,
+    pub planted_gears: usize,
+    pub seed: u64,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            width: 140,
+            height: 140,
+            number_density: 0.1,
+            symbol_density: 0.05,
+            symbols: "*#+$/@=%&-".chars().collect(),
+            planted_gears: 0,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacedNumber {
+    pub value: i64,
+    pub row: usize,
+    pub col: usize,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedSchematic {
+    pub text: String,
+    pub numbers: Vec<PlacedNumber>,
+    pub symbols: Vec<(Point, char)>,
+    pub planted_gears: Vec<(Point, i64)>,
+    pub part_number_sum: i64,
+    pub gear_ratio_sum: i64,
+}
+
+impl PlacedNumber {
+    pub fn touches(&self, point: Point) -> bool {
+        point.row + 1 >= self.row
+            && point.row <= self.row + 1
+            && point.col + 1 >= self.col
+            && point.col <= self.col + self.length
+    }
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    chars: Vec<char>,
+    reserved: Vec<bool>,
+    numbers: Vec<PlacedNumber>,
+    symbols: Vec<(Point, char)>,
+}
+
+impl Canvas {
+    fn is_free(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width && !self.reserved[row * self.width + col]
+    }
+
+    fn is_digit(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width && self.chars[row * self.width + col].is_ascii_digit()
+    }
+
+    fn put_number(&mut self, number: PlacedNumber) {
+        let start = number.row * self.width + number.col;
+        for (offset, c) in number.value.to_string().chars().enumerate() {
+            self.chars[start + offset] = c;
+            self.reserved[start + offset] = true;
+        }
+        self.numbers.push(number);
+    }
+
+    fn put_symbol(&mut self, point: Point, symbol: char) {
+        let index = point.row * self.width + point.col;
+        self.chars[index] = symbol;
+        self.reserved[index] = true;
+        self.symbols.push((point, symbol));
+    }
+
+    fn reserve_around(&mut self, number: &PlacedNumber) {
+        for row in number.row.saturating_sub(1)..=number.row + 1 {
+            for col in number.col.saturating_sub(1)..=number.col + number.length {
+                if row < self.height && col < self.width {
+                    self.reserved[row * self.width + col] = true;
+                }
+            }
+        }
+    }
+}
+
+pub fn generate(config: &GeneratorConfig) -> Result<GeneratedSchematic> {
+    for (name, density) in [
+        ("Number", config.number_density),
+        ("Symbol", config.symbol_density),
+    ] {
+        ensure!(
+            (0.0..=1.0).contains(&density),
+            "{name} density {density} is not between 0 and 1."
+        );
+    }
+    if let Some(symbol) = config
+        .symbols
+        .iter()
+        .find(|symbol| **symbol == '.' || symbol.is_ascii_digit() || symbol.is_whitespace())
+    {
+        bail!("Symbol {symbol:?} is a blank, a digit or whitespace.");
+    }
+    let size = config.width.checked_mul(config.height).ok_or_else(|| {
+        anyhow!(
+            "A {}x{} schematic is too large.",
+            config.width,
+            config.height
+        )
+    })?;
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut canvas = Canvas {
+        width: config.width,
+        height: config.height,
+        chars: vec!['.'; size],
+        reserved: vec![false; size],
+        numbers: vec![],
+        symbols: vec![],
+    };
+    let planted_gears = (0..config.planted_gears)
+        .filter_map(|_| plant_gear(&mut rng, &mut canvas))
+        .collect();
+    fill(&mut rng, &mut canvas, config);
+
+    let part_number_sum = canvas
+        .numbers
+        .iter()
+        .filter(|number| {
+            canvas
+                .symbols
+                .iter()
+                .any(|(point, _)| number.touches(*point))
+        })
+        .map(|number| number.value)
+        .sum();
+    let gear_ratio_sum = canvas
+        .symbols
+        .iter()
+        .filter(|(_, symbol)| *symbol == '*')
+        .filter_map(|(point, _)| {
+            let values: Vec<i64> = canvas
+                .numbers
+                .iter()
+                .filter(|number| number.touches(*point))
+                .map(|number| number.value)
+                .collect();
+            (values.len() == 2).then(|| values[0] * values[1])
+        })
+        .sum();
+    let text = canvas
+        .chars
+        .chunks(config.width.max(1))
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect();
+
+    Ok(GeneratedSchematic {
+        text,
+        numbers: canvas.numbers,
+        symbols: canvas.symbols,
+        planted_gears,
+        part_number_sum,
+        gear_ratio_sum,
+    })
+}
+
+fn random_number<R: Rng>(rng: &mut R, row: usize, col: usize, length: usize) -> PlacedNumber {
+    let low = 10i64.pow(length as u32 - 1);
+    let value = rng.gen_range(low.max(1)..low * 10);
+    PlacedNumber {
+        value,
+        row,
+        col,
+        length,
+    }
+}
+
+fn plant_gear<R: Rng>(rng: &mut R, canvas: &mut Canvas) -> Option<(Point, i64)> {
+    if canvas.height < 3 || canvas.width < 2 {
+        return None;
+    }
+    for _ in 0..100 {
+        let point = Point::new(
+            rng.gen_range(1..canvas.height - 1),
+            rng.gen_range(0..canvas.width),
+        );
+        let above_length = rng.gen_range(1..=3.min(point.col + 1));
+        let below_length = rng.gen_range(1..=3.min(canvas.width - point.col));
+        let above = random_number(
+            rng,
+            point.row - 1,
+            point.col + 1 - above_length,
+            above_length,
+        );
+        let below = random_number(rng, point.row + 1, point.col, below_length);
+        let rows = point.row.saturating_sub(2)..=point.row + 2;
+        let cols = above.col.saturating_sub(1)..=below.col + below.length;
+        if !rows.clone().all(|row| {
+            cols.clone()
+                .all(|col| row >= canvas.height || col >= canvas.width || canvas.is_free(row, col))
+        }) {
+            continue;
+        }
+        let ratio = above.value * below.value;
+        canvas.put_symbol(point, '*');
+        canvas.reserve_around(&above);
+        canvas.reserve_around(&below);
+        canvas.put_number(above);
+        canvas.put_number(below);
+        return Some((point, ratio));
+    }
+    None
+}
+
+fn fill<R: Rng>(rng: &mut R, canvas: &mut Canvas, config: &GeneratorConfig) {
+    for row in 0..canvas.height {
+        let mut col = 0;
+        while col < canvas.width {
+            if !canvas.is_free(row, col) {
+                col += 1;
+                continue;
+            }
+            if rng.gen_bool(config.number_density) {
+                let length = rng.gen_range(1..=3);
+                let fits = (col..col + length).all(|col| canvas.is_free(row, col));
+                let separated = (col == 0 || !canvas.is_digit(row, col - 1))
+                    && !canvas.is_digit(row, col + length);
+                if fits && separated {
+                    canvas.put_number(random_number(rng, row, col, length));
+                    col += length + 1;
+                    continue;
+                }
+            } else if rng.gen_bool(config.symbol_density) {
+                if let Some(&symbol) = config.symbols.choose(rng) {
+                    canvas.put_symbol(Point::new(row, col), symbol);
+                }
+            }
+            col += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sum_gear_ratios, sum_part_numbers, Schematic};
+    use rstest::*;
+    use std::str::FromStr;
+
+    fn solve(schematic: &Schematic) -> (i64, i64) {
+        (
+            sum_part_numbers(schematic).unwrap(),
+            sum_gear_ratios(schematic).unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_expected_sums_match_solver(
+        #[values(0, 1, 2, 3, 4)] seed: u64,
+        #[values(0.05, 0.2, 0.5)] number_density: f64,
+    ) {
+        let config = GeneratorConfig {
+            width: 40,
+            height: 30,
+            number_density,
+            symbol_density: 0.15,
+            planted_gears: 5,
+            seed,
+            ..Default::default()
+        };
+        let generated = generate(&config).unwrap();
+        let schematic = Schematic::from_str(&generated.text).unwrap();
+        assert_eq!(schematic.width(), 40);
+        assert_eq!(schematic.height(), 30);
+        assert_eq!(schematic.numbers.len(), generated.numbers.len());
+        assert_eq!(
+            solve(&schematic),
+            (generated.part_number_sum, generated.gear_ratio_sum)
+        );
+    }
+
+    #[rstest]
+    fn test_planted_gears(#[values(0, 7, 42)] seed: u64) {
+        let config = GeneratorConfig {
+            width: 60,
+            height: 60,
+            planted_gears: 10,
+            seed,
+            ..Default::default()
+        };
+        let generated = generate(&config).unwrap();
+        let schematic = Schematic::from_str(&generated.text).unwrap();
+        assert_eq!(generated.planted_gears.len(), 10);
+        for (point, ratio) in &generated.planted_gears {
+            assert_eq!(schematic.gear_ratio(*point), Ok(Some(*ratio)));
+        }
+    }
+
+    #[rstest]
+    fn test_only_planted_gears() {
+        let config = GeneratorConfig {
+            width: 30,
+            height: 30,
+            number_density: 0.0,
+            symbol_density: 0.0,
+            planted_gears: 4,
+            ..Default::default()
+        };
+        let generated = generate(&config).unwrap();
+        let ratios: i64 = generated.planted_gears.iter().map(|(_, ratio)| ratio).sum();
+        assert_eq!(generated.gear_ratio_sum, ratios);
+        assert_eq!(
+            generated.part_number_sum,
+            generated
+                .numbers
+                .iter()
+                .map(|number| number.value)
+                .sum::<i64>()
+        );
+    }
+
+    #[rstest]
+    fn test_deterministic() {
+        let config = GeneratorConfig {
+            width: 20,
+            height: 20,
+            seed: 3,
+            ..Default::default()
+        };
+        assert_eq!(generate(&config).unwrap(), generate(&config).unwrap());
+        let other = GeneratorConfig {
+            seed: 4,
+            ..config.clone()
+        };
+        assert_ne!(
+            generate(&config).unwrap().text,
+            generate(&other).unwrap().text
+        );
+    }
+
+    #[rstest]
+    #[case(GeneratorConfig { number_density: 1.5, ..Default::default() })]
+    #[case(GeneratorConfig { symbol_density: -0.1, ..Default::default() })]
+    #[case(GeneratorConfig { number_density: f64::NAN, ..Default::default() })]
+    #[case(GeneratorConfig { symbols: vec!['*', '.'], ..Default::default() })]
+    #[case(GeneratorConfig { symbols: vec!['7'], ..Default::default() })]
+    #[case(GeneratorConfig { symbols: vec!['\n'], ..Default::default() })]
+    #[case(GeneratorConfig { width: usize::MAX, height: 2, ..Default::default() })]
+    fn test_rejects_invalid_config(#[case] config: GeneratorConfig) {
+        assert!(generate(&config).is_err());
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(2, 5)]
+    #[case(5, 1)]
+    fn test_tiny_grids(#[case] width: usize, #[case] height: usize) {
+        let config = GeneratorConfig {
+            width,
+            height,
+            number_density: 0.5,
+            symbol_density: 0.5,
+            planted_gears: 2,
+            ..Default::default()
+        };
+        let generated = generate(&config).unwrap();
+        let schematic = Schematic::from_str(&generated.text).unwrap();
+        assert_eq!(
+            solve(&schematic),
+            (generated.part_number_sum, generated.gear_ratio_sum)
+        );
+    }
+}
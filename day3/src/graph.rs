@@ -0,0 +1,199 @@
+use crate::{grid::Point, Cell, Schematic};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[derive(Debug)]
+pub struct AdjacencyGraph<'a> {
+    pub schematic: &'a Schematic,
+    pub symbols: Vec<(Point, char)>,
+    symbol_indices: HashMap<Point, usize>,
+    number_edges: Vec<Vec<usize>>,
+    symbol_edges: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Component {
+    pub numbers: Vec<usize>,
+    pub symbols: Vec<usize>,
+}
+
+impl Schematic {
+    pub fn adjacency_graph(&self) -> AdjacencyGraph<'_> {
+        let symbols: Vec<(Point, char)> = self.symbols().collect();
+        let symbol_indices: HashMap<Point, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(index, (point, _))| (*point, index))
+            .collect();
+        let mut number_edges = vec![vec![]; self.numbers.len()];
+        let mut symbol_edges = vec![vec![]; symbols.len()];
+        for (number_index, number) in self.numbers.iter().enumerate() {
+            for (point, _) in self.symbols_adjacent_to(number) {
+                let symbol_index = symbol_indices[&point];
+                number_edges[number_index].push(symbol_index);
+                symbol_edges[symbol_index].push(number_index);
+            }
+        }
+        AdjacencyGraph {
+            schematic: self,
+            symbols,
+            symbol_indices,
+            number_edges,
+            symbol_edges,
+        }
+    }
+}
+
+impl AdjacencyGraph<'_> {
+    pub fn symbol_index(&self, point: Point) -> Option<usize> {
+        self.symbol_indices.get(&point).copied()
+    }
+
+    pub fn number_index(&self, point: Point) -> Option<usize> {
+        match self.schematic.grid.get(point) {
+            Some(Cell::Number(_, index)) => Some(*index),
+            _ => None,
+        }
+    }
+
+    pub fn symbols_touching(&self, number: usize) -> &[usize] {
+        &self.number_edges[number]
+    }
+
+    pub fn numbers_touching(&self, symbol: usize) -> &[usize] {
+        &self.symbol_edges[symbol]
+    }
+
+    pub fn components(&self) -> Vec<Component> {
+        let mut seen_numbers = vec![false; self.number_edges.len()];
+        let mut seen_symbols = vec![false; self.symbol_edges.len()];
+        let mut components = vec![];
+        for start in 0..self.number_edges.len() {
+            if seen_numbers[start] || self.number_edges[start].is_empty() {
+                continue;
+            }
+            let mut component = Component {
+                numbers: vec![],
+                symbols: vec![],
+            };
+            seen_numbers[start] = true;
+            let mut queue = vec![start];
+            while let Some(number) = queue.pop() {
+                component.numbers.push(number);
+                for &symbol in &self.number_edges[number] {
+                    if seen_symbols[symbol] {
+                        continue;
+                    }
+                    seen_symbols[symbol] = true;
+                    component.symbols.push(symbol);
+                    for &neighbor in &self.symbol_edges[symbol] {
+                        if !seen_numbers[neighbor] {
+                            seen_numbers[neighbor] = true;
+                            queue.push(neighbor);
+                        }
+                    }
+                }
+            }
+            component.numbers.sort();
+            component.symbols.sort();
+            components.push(component);
+        }
+        components
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph schematic {\n");
+        for (index, number) in self.schematic.numbers.iter().enumerate() {
+            writeln!(
+                dot,
+                "    n{index} [label=\"{} ({},{})\", shape=box];",
+                number.value, number.row, number.col
+            )
+            .unwrap();
+        }
+        for (index, (point, symbol)) in self.symbols.iter().enumerate() {
+            let symbol = match symbol {
+                '"' | '\\' => format!("\\{symbol}"),
+                _ => symbol.to_string(),
+            };
+            writeln!(
+                dot,
+                "    s{index} [label=\"{symbol} ({},{})\", shape=circle];",
+                point.row, point.col
+            )
+            .unwrap();
+        }
+        for (number, symbols) in self.number_edges.iter().enumerate() {
+            for symbol in symbols {
+                writeln!(dot, "    n{number} -- s{symbol};").unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[fixture]
+    fn schematic() -> Schematic {
+        Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap()
+    }
+
+    #[rstest]
+    fn test_adjacency(schematic: Schematic) {
+        let graph = schematic.adjacency_graph();
+        assert_eq!(graph.symbols.len(), 6);
+        assert_eq!(graph.symbols_touching(0), &[0]);
+        assert_eq!(graph.symbols_touching(1), &[] as &[usize]);
+        let gear = graph.symbol_index(Point::new(8, 5)).unwrap();
+        assert_eq!(graph.numbers_touching(gear), &[7, 9]);
+        assert_eq!(graph.number_index(Point::new(9, 6)), Some(9));
+    }
+
+    #[rstest]
+    fn test_components(schematic: Schematic) {
+        let components = schematic.adjacency_graph().components();
+        assert_eq!(components.len(), 6);
+        assert_eq!(
+            components[0],
+            Component {
+                numbers: vec![0, 2],
+                symbols: vec![0]
+            }
+        );
+        assert_eq!(
+            components[4],
+            Component {
+                numbers: vec![7, 9],
+                symbols: vec![5]
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_components_merge_through_shared_numbers() {
+        let schematic = Schematic::from_str("*12#..\n...4..\n....$.").unwrap();
+        let components = schematic.adjacency_graph().components();
+        assert_eq!(
+            components,
+            vec![Component {
+                numbers: vec![0, 1],
+                symbols: vec![0, 1, 2]
+            }]
+        );
+    }
+
+    #[rstest]
+    fn test_to_dot(schematic: Schematic) {
+        let dot = schematic.adjacency_graph().to_dot();
+        assert!(dot.starts_with("graph schematic {\n    n0 [label=\"467 (0,0)\", shape=box];\n"));
+        assert!(dot.contains("    s5 [label=\"* (8,5)\", shape=circle];\n"));
+        assert!(dot.contains("    n9 -- s5;\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -0,0 +1,384 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum Neighborhood {
+    VonNeumann,
+    #[default]
+    Moore,
+    Chebyshev(usize),
+    Manhattan(usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl Point {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn offset(&self, row_offset: isize, col_offset: isize) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add_signed(row_offset)?,
+            col: self.col.checked_add_signed(col_offset)?,
+        })
+    }
+}
+
+impl Neighborhood {
+    pub fn radius(&self) -> usize {
+        match *self {
+            Neighborhood::VonNeumann | Neighborhood::Moore => 1,
+            Neighborhood::Chebyshev(radius) | Neighborhood::Manhattan(radius) => radius,
+        }
+    }
+
+    pub fn contains(&self, row_offset: isize, col_offset: isize) -> bool {
+        self.reaches(row_offset.unsigned_abs(), col_offset.unsigned_abs())
+    }
+
+    fn reaches(&self, rows: usize, cols: usize) -> bool {
+        match *self {
+            Neighborhood::VonNeumann => rows + cols <= 1,
+            Neighborhood::Moore => rows.max(cols) <= 1,
+            Neighborhood::Chebyshev(radius) => rows.max(cols) <= radius,
+            Neighborhood::Manhattan(radius) => rows + cols <= radius,
+        }
+    }
+
+    pub fn around(
+        self,
+        point: Point,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = Point> {
+        let radius = self.radius();
+        let window = |center: usize, bounds: Range<usize>| {
+            center.saturating_sub(radius).max(bounds.start)
+                ..center
+                    .saturating_add(radius)
+                    .saturating_add(1)
+                    .min(bounds.end)
+        };
+        let cols = window(point.col, cols);
+        window(point.row, rows)
+            .flat_map(move |row| cols.clone().map(move |col| Point { row, col }))
+            .filter(move |&neighbor| {
+                neighbor != point
+                    && self.reaches(
+                        neighbor.row.abs_diff(point.row),
+                        neighbor.col.abs_diff(point.col),
+                    )
+            })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            self.cells.get(point.row * self.width + point.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            self.cells.get_mut(point.row * self.width + point.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        if row < self.height {
+            Some(&mut self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn insert_row(&mut self, row: usize, cells: Vec<T>) {
+        assert!(
+            row <= self.height,
+            "cannot insert row {row} into {} rows",
+            self.height
+        );
+        if self.height == 0 {
+            self.width = cells.len();
+        }
+        assert_eq!(
+            cells.len(),
+            self.width,
+            "inserted row must match the grid width"
+        );
+        let start = row * self.width;
+        self.cells.splice(start..start, cells);
+        self.height += 1;
+    }
+
+    pub fn remove_row(&mut self, row: usize) -> Vec<T> {
+        assert!(
+            row < self.height,
+            "cannot remove row {row} from {} rows",
+            self.height
+        );
+        let start = row * self.width;
+        let removed = self.cells.drain(start..start + self.width).collect();
+        self.height -= 1;
+        removed
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(width.max(1))
+            .take(if col < width { self.height } else { 0 })
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &SURROUNDING)
+    }
+
+    pub fn neighborhood(
+        &self,
+        point: Point,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Point> + '_ {
+        neighborhood.around(point, 0..self.height, 0..self.width)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(row_offset, col_offset)| point.offset(row_offset, col_offset))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (row_number, row) in self.rows().enumerate() {
+            if row_number > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn grid() -> Grid<char> {
+        Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[rstest]
+    fn test_from_rows_rejects_ragged_rows() {
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Some('a'))]
+    #[case(Point::new(1, 2), Some('f'))]
+    #[case(Point::new(2, 0), None)]
+    #[case(Point::new(0, 3), None)]
+    fn test_get(grid: Grid<char>, #[case] point: Point, #[case] expected: Option<char>) {
+        assert_eq!(grid.get(point).copied(), expected);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), vec![Point::new(0, 1), Point::new(1, 0)])]
+    #[case(Point::new(1, 1), vec![Point::new(0, 1), Point::new(1, 0), Point::new(1, 2)])]
+    fn test_neighbors4(grid: Grid<char>, #[case] point: Point, #[case] expected: Vec<Point>) {
+        assert_eq!(grid.neighbors4(point).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), vec![Point::new(0, 1), Point::new(1, 0), Point::new(1, 1)])]
+    #[case(Point::new(1, 1), vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(1, 0), Point::new(1, 2)])]
+    fn test_neighbors8(grid: Grid<char>, #[case] point: Point, #[case] expected: Vec<Point>) {
+        assert_eq!(grid.neighbors8(point).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case(Neighborhood::VonNeumann, 4)]
+    #[case(Neighborhood::Moore, 8)]
+    #[case(Neighborhood::Chebyshev(2), 24)]
+    #[case(Neighborhood::Manhattan(2), 12)]
+    fn test_neighborhood_sizes(#[case] neighborhood: Neighborhood, #[case] expected: usize) {
+        let grid = Grid::new(5, 5, ());
+        assert_eq!(
+            grid.neighborhood(Point::new(2, 2), neighborhood).count(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Neighborhood::Chebyshev(usize::MAX), 24)]
+    #[case(Neighborhood::Manhattan(usize::MAX), 24)]
+    #[case(Neighborhood::Chebyshev(1), 3)]
+    fn test_neighborhood_clamps_to_grid(
+        #[case] neighborhood: Neighborhood,
+        #[case] expected: usize,
+    ) {
+        let grid = Grid::new(5, 5, ());
+        assert_eq!(
+            grid.neighborhood(Point::new(0, 0), neighborhood).count(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_neighborhood_matches_fixed_neighbors(grid: Grid<char>) {
+        for point in grid.points() {
+            assert!(grid
+                .neighborhood(point, Neighborhood::VonNeumann)
+                .eq(grid.neighbors4(point)));
+            assert!(grid
+                .neighborhood(point, Neighborhood::Moore)
+                .eq(grid.neighbors8(point)));
+        }
+    }
+
+    #[rstest]
+    fn test_rows_and_columns(grid: Grid<char>) {
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[rstest]
+    fn test_insert_and_remove_rows(mut grid: Grid<char>) {
+        grid.insert_row(1, vec!['x', 'y', 'z']);
+        assert_eq!(grid.to_string(), "abc\nxyz\ndef");
+        assert_eq!(grid.remove_row(0), vec!['a', 'b', 'c']);
+        assert_eq!(grid.to_string(), "xyz\ndef");
+        assert_eq!(grid.height(), 2);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_insert_row_rejects_other_widths(mut grid: Grid<char>) {
+        grid.insert_row(0, vec!['x']);
+    }
+
+    #[rstest]
+    fn test_display(grid: Grid<char>) {
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{error::OverflowError, grid::Point, Cell, Schematic, SchematicNumber};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection<'a> {
+    Blank(char),
+    Number {
+        number: &'a SchematicNumber,
+        symbols: Vec<(Point, char)>,
+        is_part: bool,
+    },
+    Symbol {
+        symbol: char,
+        numbers: Vec<&'a SchematicNumber>,
+        gear_ratio: Result<Option<i64>, OverflowError>,
+    },
+}
+
+impl Schematic {
+    pub fn inspect(&self, point: Point) -> Option<Selection<'_>> {
+        Some(match *self.grid.get(point)? {
+            Cell::Blank(c) => Selection::Blank(c),
+            Cell::Number(_, index) => {
+                let number = &self.numbers[index];
+                let symbols = self.symbols_adjacent_to(number);
+                Selection::Number {
+                    number,
+                    is_part: !symbols.is_empty(),
+                    symbols,
+                }
+            }
+            Cell::Symbol(symbol) => Selection::Symbol {
+                symbol,
+                numbers: self.numbers_adjacent_to(point),
+                gear_ratio: self.gear_ratio(point),
+            },
+        })
+    }
+}
+
+impl Display for Selection<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selection::Blank(c) => write!(f, "blank '{c}'"),
+            Selection::Number {
+                number,
+                symbols,
+                is_part,
+            } => {
+                writeln!(
+                    f,
+                    "number {} at {}:{} (length {})",
+                    number.value, number.row, number.col, number.length
+                )?;
+                writeln!(f, "part number: {}", if *is_part { "yes" } else { "no" })?;
+                write!(f, "adjacent symbols:")?;
+                for (point, symbol) in symbols {
+                    write!(f, "\n  '{symbol}' at {}:{}", point.row, point.col)?;
+                }
+                Ok(())
+            }
+            Selection::Symbol {
+                symbol,
+                numbers,
+                gear_ratio,
+            } => {
+                writeln!(f, "symbol '{symbol}'")?;
+                match gear_ratio {
+                    Ok(Some(ratio)) => writeln!(f, "gear ratio: {ratio}")?,
+                    Ok(None) => writeln!(f, "gear ratio: -")?,
+                    Err(err) => writeln!(f, "gear ratio: {err}")?,
+                }
+                write!(f, "adjacent numbers:")?;
+                for number in numbers {
+                    write!(f, "\n  {} at {}:{}", number.value, number.row, number.col)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[fixture]
+    fn schematic() -> Schematic {
+        Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap()
+    }
+
+    #[rstest]
+    fn test_inspect_blank(schematic: Schematic) {
+        assert_eq!(
+            schematic.inspect(Point::new(0, 3)),
+            Some(Selection::Blank('.'))
+        );
+        assert_eq!(schematic.inspect(Point::new(10, 0)), None);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 1), 467, true)]
+    #[case(Point::new(0, 7), 114, false)]
+    fn test_inspect_number(
+        schematic: Schematic,
+        #[case] point: Point,
+        #[case] value: i64,
+        #[case] expected_part: bool,
+    ) {
+        match schematic.inspect(point) {
+            Some(Selection::Number {
+                number, is_part, ..
+            }) => {
+                assert_eq!(number.value, value);
+                assert_eq!(is_part, expected_part);
+            }
+            other => panic!("expected a number, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(Point::new(1, 3), Some(16345))]
+    #[case(Point::new(4, 3), None)]
+    #[case(Point::new(3, 6), None)]
+    fn test_inspect_symbol(
+        schematic: Schematic,
+        #[case] point: Point,
+        #[case] expected: Option<i64>,
+    ) {
+        match schematic.inspect(point) {
+            Some(Selection::Symbol { gear_ratio, .. }) => assert_eq!(gear_ratio, Ok(expected)),
+            other => panic!("expected a symbol, got {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_display(schematic: Schematic) {
+        assert_eq!(
+            schematic.inspect(Point::new(1, 3)).unwrap().to_string(),
+            "symbol '*'\ngear ratio: 16345\nadjacent numbers:\n  467 at 0:0\n  35 at 2:2"
+        );
+        assert_eq!(
+            schematic.inspect(Point::new(0, 5)).unwrap().to_string(),
+            "number 114 at 0:5 (length 3)\npart number: no\nadjacent symbols:"
+        );
+    }
+}
@@ -1,28 +1,54 @@
+pub mod config;
+pub mod edit;
+pub mod error;
+pub mod export;
+pub mod gear;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod inspect;
+pub mod reference;
+pub mod render;
+pub mod stream;
+
+use config::SchematicConfig;
+use error::{OverflowError, ParseErrorKind, ParseSchematicError};
+use grid::{Grid, Point};
 use nom::{
     branch::alt,
     bytes::complete::take_while1,
-    character::complete::{digit1, satisfy},
-    error::Error,
+    character::complete::{char, digit1, satisfy},
+    combinator::{cond, opt, recognize},
     multi::many1,
-    Finish, IResult,
+    sequence::pair,
+    IResult,
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct Schematic {
+    pub grid: Grid<Cell>,
     pub numbers: Vec<SchematicNumber>,
-    pub symbols: HashMap<(usize, usize), char>,
+    pub config: SchematicConfig,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct SchematicNumber {
-    pub value: u32,
+    pub value: i64,
     pub row: usize,
     pub col: usize,
     pub length: usize,
 }
 
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Cell {
+    Blank(char),
+    Symbol(char),
+    Number(char, usize),
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum SchematicEntry<'a> {
     Dots(&'a str),
@@ -30,73 +56,255 @@ enum SchematicEntry<'a> {
     Number(&'a str),
 }
 
+impl SchematicNumber {
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let row = self.row;
+        (self.col..self.col + self.length).map(move |col| Point { row, col })
+    }
+}
+
+impl Schematic {
+    pub fn symbols(&self) -> impl Iterator<Item = (Point, char)> + '_ {
+        self.grid.iter().filter_map(|(point, cell)| match cell {
+            Cell::Symbol(symbol) => Some((point, *symbol)),
+            _ => None,
+        })
+    }
+
+    pub fn number_at(&self, point: Point) -> Option<&SchematicNumber> {
+        match self.grid.get(point) {
+            Some(Cell::Number(_, index)) => Some(&self.numbers[*index]),
+            _ => None,
+        }
+    }
+
+    pub fn numbers_adjacent_to(&self, point: Point) -> Vec<&SchematicNumber> {
+        let mut indices: Vec<usize> = vec![];
+        for neighbor in self.grid.neighborhood(point, self.config.neighborhood) {
+            if let Cell::Number(_, index) = self.grid[neighbor] {
+                if !indices.contains(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        indices.iter().map(|&index| &self.numbers[index]).collect()
+    }
+
+    pub fn symbols_adjacent_to(&self, number: &SchematicNumber) -> Vec<(Point, char)> {
+        let mut symbols: Vec<(Point, char)> = vec![];
+        let neighborhood = self.config.neighborhood;
+        for neighbor in number
+            .points()
+            .flat_map(|point| self.grid.neighborhood(point, neighborhood))
+        {
+            if let Cell::Symbol(symbol) = self.grid[neighbor] {
+                if !symbols.contains(&(neighbor, symbol)) {
+                    symbols.push((neighbor, symbol));
+                }
+            }
+        }
+        symbols
+    }
+
+    pub fn is_part_number(&self, number: &SchematicNumber) -> bool {
+        !self.symbols_adjacent_to(number).is_empty()
+    }
+
+    pub fn gear_ratio(&self, point: Point) -> Result<Option<i64>, OverflowError> {
+        self.gear_value(point, &gear::GearRule::default())
+    }
+}
+
+impl Schematic {
+    pub fn parse_with(s: &str, config: &SchematicConfig) -> Result<Self, ParseSchematicError> {
+        parse_schematic_with(s, config)
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cell::Blank(c) | Cell::Symbol(c) | Cell::Number(c, _) => write!(f, "{c}"),
+        }
+    }
+}
+
+impl fmt::Display for Schematic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
 impl FromStr for Schematic {
-    type Err = Error<String>;
+    type Err = ParseSchematicError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match parse_schematic(s).finish() {
-            Ok((_remaining, schematic)) => Ok(schematic),
-            Err(Error { input, code }) => Err(Error {
-                input: input.to_string(),
-                code,
-            }),
-        }
+        Schematic::parse_with(s, &SchematicConfig::default())
     }
 }
 
-pub fn parse_schematic(s: &str) -> IResult<&str, Schematic> {
+fn checked_sum(
+    values: impl IntoIterator<Item = Result<i64, OverflowError>>,
+) -> Result<i64, OverflowError> {
+    values.into_iter().try_fold(0i64, |sum, value| {
+        sum.checked_add(value?).ok_or(OverflowError)
+    })
+}
+
+pub fn sum_part_numbers(schematic: &Schematic) -> Result<i64, OverflowError> {
+    checked_sum(
+        schematic
+            .numbers
+            .iter()
+            .filter(|number| schematic.is_part_number(number))
+            .map(|number| Ok(number.value)),
+    )
+}
+
+pub fn sum_gear_ratios(schematic: &Schematic) -> Result<i64, OverflowError> {
+    checked_sum(schematic.gears().map(|gear| gear.map(|gear| gear.ratio)))
+}
+
+pub fn parse_schematic(s: &str) -> Result<Schematic, ParseSchematicError> {
+    parse_schematic_with(s, &SchematicConfig::default())
+}
+
+pub fn parse_schematic_with(
+    s: &str,
+    config: &SchematicConfig,
+) -> Result<Schematic, ParseSchematicError> {
     let mut numbers: Vec<SchematicNumber> = vec![];
-    let mut symbols: HashMap<(usize, usize), char> = HashMap::new();
+    let mut rows: Vec<Vec<Cell>> = vec![];
 
     for (line_number, line) in s.lines().enumerate() {
-        let mut col_number = 0;
-        let (_, row) = schematic_row(line)?;
-        for entry in row {
-            match entry {
-                SchematicEntry::Dots(dots) => {
-                    col_number += dots.len();
-                }
-                SchematicEntry::Symbol(symbol) => {
-                    symbols.insert((line_number, col_number), symbol);
-                    col_number += 1;
-                }
-                SchematicEntry::Number(number) => {
-                    numbers.push(SchematicNumber {
-                        value: number.parse::<_>().unwrap(),
-                        row: line_number,
-                        col: col_number,
-                        length: number.len(),
-                    });
-                    col_number += number.len();
-                }
+        let cells = parse_row(line, line_number, config, &mut numbers)?;
+        check_width(&cells, rows.first().map(Vec::len), line_number)?;
+        rows.push(cells);
+    }
+    let grid = Grid::from_rows(rows).expect("rows are validated to share a width");
+    Ok(Schematic {
+        grid,
+        numbers,
+        config: config.clone(),
+    })
+}
+
+pub(crate) fn parse_row(
+    line: &str,
+    line_number: usize,
+    config: &SchematicConfig,
+    numbers: &mut Vec<SchematicNumber>,
+) -> Result<Vec<Cell>, ParseSchematicError> {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let error = |column, kind| ParseSchematicError {
+        line: line_number + 1,
+        column,
+        kind,
+    };
+    let mut cells: Vec<Cell> = vec![];
+    let row = match schematic_row(line, config) {
+        Ok(("", row)) => row,
+        Ok((remains, _)) | Err(nom::Err::Error(nom::error::Error { input: remains, .. })) => {
+            let column = line[..line.len() - remains.len()].chars().count() + 1;
+            return Err(match remains.chars().next() {
+                Some(c) => error(column, ParseErrorKind::UnexpectedChar(c)),
+                None => error(column, ParseErrorKind::EmptyRow),
+            });
+        }
+        Err(_) => unreachable!("complete parsers do not return Incomplete or Failure"),
+    };
+    for entry in row {
+        match entry {
+            SchematicEntry::Dots(dots) => {
+                cells.extend(dots.chars().map(Cell::Blank));
+            }
+            SchematicEntry::Symbol(symbol) => {
+                cells.push(Cell::Symbol(symbol));
+            }
+            SchematicEntry::Number(number) => {
+                let Ok(value) = number.parse::<i64>() else {
+                    let kind = ParseErrorKind::NumberOutOfRange(number.to_string());
+                    return Err(error(cells.len() + 1, kind));
+                };
+                let index = numbers.len();
+                numbers.push(SchematicNumber {
+                    value,
+                    row: line_number,
+                    col: cells.len(),
+                    length: number.len(),
+                });
+                cells.extend(number.chars().map(|digit| Cell::Number(digit, index)));
             }
         }
     }
-    Ok(("", Schematic { numbers, symbols }))
+    Ok(cells)
+}
+
+pub(crate) fn check_width(
+    cells: &[Cell],
+    expected: Option<usize>,
+    line_number: usize,
+) -> Result<(), ParseSchematicError> {
+    match expected {
+        Some(expected) if cells.len() != expected => Err(ParseSchematicError {
+            line: line_number + 1,
+            column: expected.min(cells.len()) + 1,
+            kind: ParseErrorKind::RaggedRow {
+                expected,
+                found: cells.len(),
+            },
+        }),
+        _ => Ok(()),
+    }
 }
 
-fn schematic_row(s: &str) -> IResult<&str, Vec<SchematicEntry>> {
-    many1(alt((schematic_dots, schematic_number, schematic_symbol)))(s)
+fn schematic_row<'a>(
+    s: &'a str,
+    config: &SchematicConfig,
+) -> IResult<&'a str, Vec<SchematicEntry<'a>>> {
+    many1(alt((
+        |s| schematic_dots(s, config),
+        |s| schematic_number(s, config),
+        |s| schematic_symbol(s, config),
+    )))(s)
 }
 
-fn schematic_dots(s: &str) -> IResult<&str, SchematicEntry> {
-    let (s, dots) = take_while1(|c| c == '.')(s)?;
+fn schematic_dots<'a>(
+    s: &'a str,
+    config: &SchematicConfig,
+) -> IResult<&'a str, SchematicEntry<'a>> {
+    let (s, dots) = take_while1(|c| config.is_blank(c))(s)?;
     Ok((s, SchematicEntry::Dots(dots)))
 }
 
-fn schematic_symbol(s: &str) -> IResult<&str, SchematicEntry> {
-    let (s, symbol) = satisfy(|c| !(c.is_ascii_digit() || c == '.'))(s)?;
+fn schematic_symbol<'a>(
+    s: &'a str,
+    config: &SchematicConfig,
+) -> IResult<&'a str, SchematicEntry<'a>> {
+    let (s, symbol) = satisfy(|c| config.is_symbol(c))(s)?;
     Ok((s, SchematicEntry::Symbol(symbol)))
 }
 
-fn schematic_number(s: &str) -> IResult<&str, SchematicEntry> {
-    let (s, number) = digit1(s)?;
+fn schematic_number<'a>(
+    s: &'a str,
+    config: &SchematicConfig,
+) -> IResult<&'a str, SchematicEntry<'a>> {
+    let (s, number) = recognize(pair(cond(config.signed_numbers, opt(char('-'))), digit1))(s)?;
     Ok((s, SchematicEntry::Number(number)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use grid::Neighborhood;
     use rstest::*;
 
     #[rstest]
@@ -107,7 +315,7 @@ mod tests {
         #[case] expected: SchematicEntry,
         #[case] expected_remains: &str,
     ) {
-        let (remains, entry) = schematic_number(s).unwrap();
+        let (remains, entry) = schematic_number(s, &SchematicConfig::default()).unwrap();
         assert_eq!(entry, expected);
         assert_eq!(remains, expected_remains);
     }
@@ -121,7 +329,7 @@ mod tests {
         #[case] expected: SchematicEntry,
         #[case] expected_remains: &str,
     ) {
-        let (remains, entry) = schematic_dots(s).unwrap();
+        let (remains, entry) = schematic_dots(s, &SchematicConfig::default()).unwrap();
         assert_eq!(entry, expected);
         assert_eq!(remains, expected_remains);
     }
@@ -135,7 +343,7 @@ mod tests {
         #[case] expected: SchematicEntry,
         #[case] expected_remains: &str,
     ) {
-        let (remains, entry) = schematic_symbol(s).unwrap();
+        let (remains, entry) = schematic_symbol(s, &SchematicConfig::default()).unwrap();
         assert_eq!(entry, expected);
         assert_eq!(remains, expected_remains);
     }
@@ -147,8 +355,213 @@ mod tests {
         #[case] expected: Vec<SchematicEntry>,
         #[case] expected_remains: &str,
     ) {
-        let (remains, row) = schematic_row(s).unwrap();
+        let (remains, row) = schematic_row(s, &SchematicConfig::default()).unwrap();
         assert_eq!(row, expected);
         assert_eq!(remains, expected_remains);
     }
+
+    #[rstest]
+    #[case(Point::new(1, 3), vec![467, 35])]
+    #[case(Point::new(3, 6), vec![633])]
+    #[case(Point::new(4, 3), vec![617])]
+    #[case(Point::new(8, 5), vec![755, 598])]
+    #[case(Point::new(0, 9), vec![])]
+    fn test_numbers_adjacent_to(#[case] point: Point, #[case] expected_values: Vec<i64>) {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let values: Vec<i64> = schematic
+            .numbers_adjacent_to(point)
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, expected_values);
+    }
+
+    #[rstest]
+    #[case(467, vec![(Point::new(1, 3), '*')])]
+    #[case(114, vec![])]
+    #[case(592, vec![(Point::new(5, 5), '+')])]
+    fn test_symbols_adjacent_to(#[case] value: i64, #[case] expected: Vec<(Point, char)>) {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        let number = schematic
+            .numbers
+            .iter()
+            .find(|number| number.value == value)
+            .unwrap();
+        assert_eq!(schematic.symbols_adjacent_to(number), expected);
+    }
+
+    #[rstest]
+    #[case(include_str!("../puzzle_inputs/sample.txt"), 4361, 467835)]
+    #[case(include_str!("../puzzle_inputs/input.txt"), 525119, 76504829)]
+    fn test_sums(#[case] input: &str, #[case] expected_parts: i64, #[case] expected_gears: i64) {
+        let schematic = Schematic::from_str(input).unwrap();
+        assert_eq!(sum_part_numbers(&schematic), Ok(expected_parts));
+        assert_eq!(sum_gear_ratios(&schematic), Ok(expected_gears));
+    }
+
+    #[rstest]
+    #[case("9999999999*9999999999", Ok(19999999998), Err(OverflowError))]
+    #[case("9223372036854775807*1", Err(OverflowError), Ok(9223372036854775807))]
+    #[case("3037000499*3037000499", Ok(6074000998), Ok(9223372030926249001))]
+    fn test_sums_overflow(
+        #[case] input: &str,
+        #[case] expected_parts: Result<i64, OverflowError>,
+        #[case] expected_gears: Result<i64, OverflowError>,
+    ) {
+        let schematic = Schematic::from_str(input).unwrap();
+        assert_eq!(sum_part_numbers(&schematic), expected_parts);
+        assert_eq!(sum_gear_ratios(&schematic), expected_gears);
+    }
+
+    #[rstest]
+    fn test_display_round_trip() {
+        let input = include_str!("../puzzle_inputs/sample.txt");
+        let schematic = Schematic::from_str(input).unwrap();
+        assert_eq!(schematic.to_string(), input.trim_end());
+    }
+
+    #[rstest]
+    #[case(Point::new(1, 3), Some(16345))]
+    #[case(Point::new(4, 3), None)]
+    #[case(Point::new(8, 5), Some(451490))]
+    #[case(Point::new(3, 6), None)]
+    #[case(Point::new(0, 0), None)]
+    fn test_gear_ratio(#[case] point: Point, #[case] expected: Option<i64>) {
+        let schematic = Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap();
+        assert_eq!(schematic.gear_ratio(point), Ok(expected));
+    }
+
+    #[rstest]
+    fn test_parse_crlf() {
+        let input = include_str!("../puzzle_inputs/sample.txt");
+        let crlf = Schematic::from_str(&input.replace('\n', "\r\n")).unwrap();
+        let lf = Schematic::from_str(input).unwrap();
+        assert_eq!(crlf.grid, lf.grid);
+        assert_eq!(crlf.grid.width(), 10);
+    }
+
+    #[rstest]
+    fn test_parse_with_config() {
+        let config = SchematicConfig {
+            blanks: vec![' '],
+            symbols: config::Symbols::Only(vec!['#', '@']),
+            gears: vec!['@'],
+            ..Default::default()
+        };
+        let schematic = Schematic::parse_with("12@3  \n  # 45", &config).unwrap();
+        let symbols: Vec<(Point, char)> = schematic.symbols().collect();
+        assert_eq!(
+            symbols,
+            vec![(Point::new(0, 2), '@'), (Point::new(1, 2), '#')]
+        );
+        assert_eq!(schematic.gear_ratio(Point::new(0, 2)), Ok(Some(36)));
+        assert_eq!(schematic.gear_ratio(Point::new(1, 2)), Ok(None));
+    }
+
+    #[rstest]
+    #[case("..\n.\n", 2, 2, ParseErrorKind::RaggedRow { expected: 2, found: 1 })]
+    #[case("..\n...", 2, 3, ParseErrorKind::RaggedRow { expected: 2, found: 3 })]
+    #[case("..\n\n..", 2, 1, ParseErrorKind::EmptyRow)]
+    #[case("12.\n.. ", 2, 3, ParseErrorKind::UnexpectedChar(' '))]
+    #[case("\t..", 1, 1, ParseErrorKind::UnexpectedChar('\t'))]
+    fn test_parse_errors(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] kind: ParseErrorKind,
+    ) {
+        let err = Schematic::from_str(input).unwrap_err();
+        assert_eq!(err, ParseSchematicError { line, column, kind });
+    }
+
+    #[rstest]
+    fn test_parse_error_display() {
+        let err = Schematic::from_str("..\n.\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:2: row is 1 cells wide, expected 2");
+    }
+
+    #[rstest]
+    #[case("", 0, 0)]
+    #[case(include_str!("../puzzle_inputs/sample.txt"), 10, 10)]
+    #[case("1.\n.*\n..", 2, 3)]
+    fn test_dimensions(#[case] input: &str, #[case] width: usize, #[case] height: usize) {
+        let schematic = Schematic::from_str(input).unwrap();
+        assert_eq!(schematic.width(), width);
+        assert_eq!(schematic.height(), height);
+    }
+
+    #[rstest]
+    #[case(false, "-12.", vec![12], vec!['-'])]
+    #[case(true, "-12.", vec![-12], vec![])]
+    #[case(true, "5-3*", vec![5, -3], vec!['*'])]
+    #[case(true, "-.-1", vec![-1], vec!['-'])]
+    #[case(false, "9876543210", vec![9876543210], vec![])]
+    fn test_signed_numbers(
+        #[case] signed_numbers: bool,
+        #[case] input: &str,
+        #[case] expected_values: Vec<i64>,
+        #[case] expected_symbols: Vec<char>,
+    ) {
+        let config = SchematicConfig {
+            signed_numbers,
+            ..Default::default()
+        };
+        let schematic = Schematic::parse_with(input, &config).unwrap();
+        let values: Vec<i64> = schematic
+            .numbers
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        let symbols: Vec<char> = schematic.symbols().map(|(_, symbol)| symbol).collect();
+        assert_eq!(values, expected_values);
+        assert_eq!(symbols, expected_symbols);
+        assert_eq!(schematic.to_string(), input);
+    }
+
+    #[rstest]
+    fn test_number_out_of_range() {
+        let err = Schematic::from_str("..99999999999999999999").unwrap_err();
+        assert_eq!(
+            err,
+            ParseSchematicError {
+                line: 1,
+                column: 3,
+                kind: ParseErrorKind::NumberOutOfRange("99999999999999999999".to_string())
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(Neighborhood::Moore, 4361, 467835)]
+    #[case(Neighborhood::VonNeumann, 2547, 0)]
+    #[case(Neighborhood::Manhattan(1), 2547, 0)]
+    #[case(Neighborhood::Chebyshev(1), 4361, 467835)]
+    #[case(Neighborhood::Manhattan(2), 4361 + 58, 467835)]
+    #[case(Neighborhood::Chebyshev(2), 4361 + 114 + 58, 0)]
+    #[case(Neighborhood::Chebyshev(usize::MAX), 4361 + 114 + 58, 0)]
+    #[case(Neighborhood::Manhattan(usize::MAX), 4361 + 114 + 58, 0)]
+    fn test_neighborhoods(
+        #[case] neighborhood: Neighborhood,
+        #[case] expected_parts: i64,
+        #[case] expected_gears: i64,
+    ) {
+        let config = SchematicConfig {
+            neighborhood,
+            ..Default::default()
+        };
+        let schematic =
+            Schematic::parse_with(include_str!("../puzzle_inputs/sample.txt"), &config).unwrap();
+        let parts: i64 = schematic
+            .numbers
+            .iter()
+            .filter(|number| schematic.is_part_number(number))
+            .map(|number| number.value)
+            .sum();
+        let gears: i64 = schematic
+            .symbols()
+            .filter_map(|(point, _)| schematic.gear_ratio(point).unwrap())
+            .sum();
+        assert_eq!(parts, expected_parts);
+        assert_eq!(gears, expected_gears);
+    }
 }
@@ -0,0 +1,261 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Counterexample {
+    pub input: String,
+    pub expected: Option<(i64, i64)>,
+    pub actual: Option<(i64, i64)>,
+}
+
+struct Number {
+    value: i64,
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+fn char_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit() && !c.is_whitespace()
+}
+
+fn find_numbers(grid: &[Vec<char>]) -> Option<Vec<Number>> {
+    let mut numbers = vec![];
+    for (row, line) in grid.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if line[col].is_ascii_digit() {
+                let start = col;
+                let mut value = 0;
+                while col < line.len() && line[col].is_ascii_digit() {
+                    value = i64::checked_mul(value, 10)?
+                        .checked_add(line[col].to_digit(10).unwrap() as i64)?;
+                    col += 1;
+                }
+                numbers.push(Number {
+                    value,
+                    row,
+                    start,
+                    end: col,
+                });
+            } else {
+                col += 1;
+            }
+        }
+    }
+    Some(numbers)
+}
+
+fn touches(number: &Number, row: usize, col: usize) -> bool {
+    row + 1 >= number.row && row <= number.row + 1 && col + 1 >= number.start && col <= number.end
+}
+
+fn cells(grid: &[Vec<char>]) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(row, line)| line.iter().enumerate().map(move |(col, &c)| (row, col, c)))
+}
+
+pub fn sum_part_numbers(input: &str) -> Option<i64> {
+    let grid = char_grid(input);
+    find_numbers(&grid)?
+        .iter()
+        .filter(|number| {
+            cells(&grid).any(|(row, col, c)| is_symbol(c) && touches(number, row, col))
+        })
+        .try_fold(0i64, |sum, number| sum.checked_add(number.value))
+}
+
+pub fn sum_gear_ratios(input: &str) -> Option<i64> {
+    let grid = char_grid(input);
+    let numbers = find_numbers(&grid)?;
+    let ratios = cells(&grid)
+        .filter(|&(_, _, c)| c == '*')
+        .filter_map(|(row, col, _)| {
+            let adjacent: Vec<i64> = numbers
+                .iter()
+                .filter(|number| touches(number, row, col))
+                .map(|number| number.value)
+                .collect();
+            match adjacent[..] {
+                [a, b] => Some(a.checked_mul(b)),
+                _ => None,
+            }
+        })
+        .try_fold(0i64, |sum, ratio| sum.checked_add(ratio?));
+    ratios
+}
+
+pub fn solve(input: &str) -> Option<(i64, i64)> {
+    Some((sum_part_numbers(input)?, sum_gear_ratios(input)?))
+}
+
+fn longest_row(grid: &[Vec<char>]) -> usize {
+    grid.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+pub fn minimize(input: &str, still_fails: impl Fn(&str) -> bool) -> String {
+    let mut grid = char_grid(input);
+    if grid.is_empty() {
+        return input.to_string();
+    }
+    let render = |grid: &[Vec<char>]| -> String {
+        grid.iter()
+            .map(|line| line.iter().collect::<String>() + "\n")
+            .collect()
+    };
+    loop {
+        let mut shrunk = false;
+        let mut row = 0;
+        while grid.len() > 1 && row < grid.len() {
+            let mut candidate = grid.clone();
+            candidate.remove(row);
+            if still_fails(&render(&candidate)) {
+                grid = candidate;
+                shrunk = true;
+            } else {
+                row += 1;
+            }
+        }
+        let mut col = 0;
+        while longest_row(&grid) > 1 && col < longest_row(&grid) {
+            let mut candidate = grid.clone();
+            for line in candidate.iter_mut().filter(|line| col < line.len()) {
+                line.remove(col);
+            }
+            if still_fails(&render(&candidate)) {
+                grid = candidate;
+                shrunk = true;
+            } else {
+                col += 1;
+            }
+        }
+        for row in 0..grid.len() {
+            for col in 0..grid[row].len() {
+                if grid[row][col] == '.' {
+                    continue;
+                }
+                let mut candidate = grid.clone();
+                candidate[row][col] = '.';
+                if still_fails(&render(&candidate)) {
+                    grid = candidate;
+                    shrunk = true;
+                }
+            }
+        }
+        if !shrunk {
+            return render(&grid);
+        }
+    }
+}
+
+pub fn differential(
+    input: &str,
+    candidate: impl Fn(&str) -> Option<(i64, i64)>,
+) -> Result<(), Counterexample> {
+    let disagrees = |input: &str| candidate(input) != solve(input);
+    if !disagrees(input) {
+        return Ok(());
+    }
+    let input = minimize(input, disagrees);
+    Err(Counterexample {
+        expected: solve(&input),
+        actual: candidate(&input),
+        input,
+    })
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.input.trim_end())?;
+        match self.expected {
+            Some((parts, gears)) => write!(f, "expected parts {parts}, gears {gears}; ")?,
+            None => write!(f, "expected no answer; ")?,
+        }
+        match self.actual {
+            Some((parts, gears)) => write!(f, "got parts {parts}, gears {gears}"),
+            None => write!(f, "got no answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case(include_str!("../puzzle_inputs/sample.txt"), 4361, 467835)]
+    #[case(include_str!("../puzzle_inputs/input.txt"), 525119, 76504829)]
+    #[case("12*\n.34", 46, 408)]
+    #[case("1.1\n.*.\n1.1", 4, 0)]
+    fn test_solve(#[case] input: &str, #[case] expected_parts: i64, #[case] expected_gears: i64) {
+        assert_eq!(solve(input), Some((expected_parts, expected_gears)));
+    }
+
+    #[rstest]
+    #[case("9999999999*9999999999")]
+    #[case("99999999999999999999.")]
+    fn test_solve_overflow(#[case] input: &str) {
+        assert_eq!(solve(input), None);
+    }
+
+    #[rstest]
+    fn test_minimize() {
+        let minimized = minimize(include_str!("../puzzle_inputs/sample.txt"), |input| {
+            sum_gear_ratios(input) == Some(16345)
+        });
+        assert_eq!(minimized, "467.\n...*\n..35\n");
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("\n")]
+    fn test_minimize_empty(#[case] input: &str) {
+        assert_eq!(minimize(input, |_| true), input);
+    }
+
+    #[rstest]
+    fn test_differential_reports_ragged_counterexample() {
+        let library = |input: &str| {
+            let schematic = crate::Schematic::from_str(input).ok()?;
+            Some((
+                crate::sum_part_numbers(&schematic).ok()?,
+                crate::sum_gear_ratios(&schematic).ok()?,
+            ))
+        };
+        let counterexample = differential("...\n.\n", library).unwrap_err();
+        assert_eq!(counterexample.expected, Some((0, 0)));
+        assert_eq!(counterexample.actual, None);
+        assert_eq!(counterexample.input, "\n");
+        let counterexample = differential("12*\n.34\n5\n", library).unwrap_err();
+        assert_eq!(counterexample.actual, None);
+        assert!(counterexample.input.len() <= "12*\n.34\n5\n".len());
+    }
+
+    #[rstest]
+    fn test_differential_reports_minimized_counterexample() {
+        let ignores_diagonals = |input: &str| {
+            let config = crate::config::SchematicConfig {
+                neighborhood: crate::grid::Neighborhood::VonNeumann,
+                ..Default::default()
+            };
+            let schematic = crate::Schematic::parse_with(input, &config).ok()?;
+            Some((
+                crate::sum_part_numbers(&schematic).ok()?,
+                crate::sum_gear_ratios(&schematic).ok()?,
+            ))
+        };
+        let counterexample = differential(
+            include_str!("../puzzle_inputs/sample.txt"),
+            ignores_diagonals,
+        )
+        .unwrap_err();
+        assert!(counterexample.input.len() <= 6, "{counterexample}");
+        assert_ne!(counterexample.expected, counterexample.actual);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    grid::{Grid, Point},
+    Cell, Schematic,
+};
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CellStyle {
+    Blank,
+    PartNumber,
+    IsolatedNumber,
+    Symbol,
+    Gear,
+}
+
+impl CellStyle {
+    fn ansi(&self) -> &'static str {
+        match self {
+            CellStyle::Blank => "\x1b[2m",
+            CellStyle::PartNumber => "\x1b[32m",
+            CellStyle::IsolatedNumber => "\x1b[31m",
+            CellStyle::Symbol => "\x1b[33m",
+            CellStyle::Gear => "\x1b[1;35m",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            CellStyle::Blank => "blank",
+            CellStyle::PartNumber => "part",
+            CellStyle::IsolatedNumber => "isolated",
+            CellStyle::Symbol => "symbol",
+            CellStyle::Gear => "gear",
+        }
+    }
+}
+
+pub fn classify(schematic: &Schematic) -> Grid<CellStyle> {
+    let is_part: Vec<bool> = schematic
+        .numbers
+        .iter()
+        .map(|number| schematic.is_part_number(number))
+        .collect();
+    let rows = schematic
+        .grid
+        .rows()
+        .enumerate()
+        .map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(|(col, cell)| match cell {
+                    Cell::Blank(_) => CellStyle::Blank,
+                    Cell::Number(_, index) if is_part[*index] => CellStyle::PartNumber,
+                    Cell::Number(_, _) => CellStyle::IsolatedNumber,
+                    Cell::Symbol(_) => match schematic.gear_ratio(Point { row, col }) {
+                        Ok(None) => CellStyle::Symbol,
+                        _ => CellStyle::Gear,
+                    },
+                })
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows).expect("styles mirror the schematic grid")
+}
+
+fn styled_runs<'a>(
+    cells: &'a [Cell],
+    styles: &'a [CellStyle],
+) -> impl Iterator<Item = (CellStyle, String)> + 'a {
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        let style = *styles.get(position)?;
+        let start = position;
+        while styles.get(position) == Some(&style) {
+            position += 1;
+        }
+        let text = cells[start..position].iter().map(Cell::to_string).collect();
+        Some((style, text))
+    })
+}
+
+pub fn render_ansi(schematic: &Schematic) -> String {
+    let styles = classify(schematic);
+    let mut output = String::new();
+    for (cells, styles) in schematic.grid.rows().zip(styles.rows()) {
+        for (style, text) in styled_runs(cells, styles) {
+            write!(output, "{}{text}\x1b[0m", style.ansi()).unwrap();
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub fn render_html(schematic: &Schematic) -> String {
+    let styles = classify(schematic);
+    let mut output = String::from(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<style>\n",
+        ".blank { color: #888; }\n",
+        ".part { color: #2a2; }\n",
+        ".isolated { color: #c22; }\n",
+        ".symbol { color: #c90; }\n",
+        ".gear { color: #a2a; font-weight: bold; }\n",
+        "</style>\n</head>\n<body>\n<pre class=\"schematic\">\n",
+    ));
+    for (cells, styles) in schematic.grid.rows().zip(styles.rows()) {
+        for (style, text) in styled_runs(cells, styles) {
+            let text = text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            write!(output, "<span class=\"{}\">{text}</span>", style.class()).unwrap();
+        }
+        output.push('\n');
+    }
+    output.push_str("</pre>\n</body>\n</html>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::str::FromStr;
+
+    #[fixture]
+    fn schematic() -> Schematic {
+        Schematic::from_str(include_str!("../puzzle_inputs/sample.txt")).unwrap()
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), CellStyle::PartNumber)]
+    #[case(Point::new(0, 5), CellStyle::IsolatedNumber)]
+    #[case(Point::new(0, 3), CellStyle::Blank)]
+    #[case(Point::new(1, 3), CellStyle::Gear)]
+    #[case(Point::new(4, 3), CellStyle::Symbol)]
+    #[case(Point::new(5, 7), CellStyle::IsolatedNumber)]
+    fn test_classify(schematic: Schematic, #[case] point: Point, #[case] expected: CellStyle) {
+        assert_eq!(classify(&schematic)[point], expected);
+    }
+
+    #[rstest]
+    fn test_render_ansi(schematic: Schematic) {
+        let rendered = render_ansi(&schematic);
+        let first_line = rendered.lines().next().unwrap();
+        assert_eq!(
+            first_line,
+            "\x1b[32m467\x1b[0m\x1b[2m..\x1b[0m\x1b[31m114\x1b[0m\x1b[2m..\x1b[0m"
+        );
+        assert_eq!(rendered.lines().count(), 10);
+    }
+
+    #[rstest]
+    fn test_render_html(schematic: Schematic) {
+        let rendered = render_html(&schematic);
+        assert!(rendered.contains(
+            "<span class=\"blank\">...</span><span class=\"gear\">*</span><span class=\"blank\">......</span>\n"
+        ));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    check_width,
+    config::SchematicConfig,
+    error::{OverflowError, ParseSchematicError, StreamError},
+    gear::GearRule,
+    grid::Point,
+    parse_row, Cell, SchematicNumber,
+};
+use std::collections::VecDeque;
+use std::io::{BufRead, Lines};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum StreamEvent {
+    PartNumber(SchematicNumber),
+    Gear { point: Point, value: i64 },
+}
+
+struct WindowRow {
+    cells: Vec<Cell>,
+    numbers: Vec<SchematicNumber>,
+}
+
+pub struct WindowSolver<R> {
+    lines: Lines<R>,
+    config: SchematicConfig,
+    rule: GearRule,
+    window: VecDeque<WindowRow>,
+    first_row: usize,
+    next_row: usize,
+    width: Option<usize>,
+    exhausted: bool,
+    events: VecDeque<StreamEvent>,
+}
+
+pub fn solve_stream<R: BufRead>(
+    reader: R,
+    config: &SchematicConfig,
+    rule: &GearRule,
+) -> WindowSolver<R> {
+    WindowSolver {
+        lines: reader.lines(),
+        config: config.clone(),
+        rule: rule.clone(),
+        window: VecDeque::new(),
+        first_row: 0,
+        next_row: 0,
+        width: None,
+        exhausted: false,
+        events: VecDeque::new(),
+    }
+}
+
+impl<R: BufRead> WindowSolver<R> {
+    pub fn window_len(&self) -> usize {
+        self.window.len()
+    }
+
+    fn cell(&self, point: Point) -> Option<&Cell> {
+        let row = point.row.checked_sub(self.first_row)?;
+        self.window.get(row)?.cells.get(point.col)
+    }
+
+    fn neighbors(&self, point: Point) -> impl Iterator<Item = (Point, &Cell)> + '_ {
+        let rows = self.first_row..self.first_row + self.window.len();
+        let cols = 0..self.width.unwrap_or(0);
+        self.config
+            .neighborhood
+            .around(point, rows, cols)
+            .filter_map(move |neighbor| Some((neighbor, self.cell(neighbor)?)))
+    }
+
+    fn decide_row(&mut self) -> Result<(), OverflowError> {
+        let row = &self.window[self.next_row - self.first_row];
+        let mut events = vec![];
+        for number in &row.numbers {
+            let is_part = number.points().any(|point| {
+                self.neighbors(point)
+                    .any(|(_, cell)| matches!(cell, Cell::Symbol(_)))
+            });
+            if is_part {
+                events.push(StreamEvent::PartNumber(*number));
+            }
+        }
+        for (col, cell) in row.cells.iter().enumerate() {
+            let Cell::Symbol(symbol) = cell else {
+                continue;
+            };
+            if !self.rule.selects(*symbol, &self.config) {
+                continue;
+            }
+            let point = Point {
+                row: self.next_row,
+                col,
+            };
+            let mut adjacent: Vec<(usize, usize)> = vec![];
+            for (neighbor, cell) in self.neighbors(point) {
+                if let Cell::Number(_, index) = cell {
+                    if !adjacent.contains(&(neighbor.row, *index)) {
+                        adjacent.push((neighbor.row, *index));
+                    }
+                }
+            }
+            let values: Vec<i64> = adjacent
+                .iter()
+                .map(|&(row, index)| self.window[row - self.first_row].numbers[index].value)
+                .collect();
+            if let Some(value) = self.rule.evaluate(&values)? {
+                events.push(StreamEvent::Gear { point, value });
+            }
+        }
+        self.events.extend(events);
+        Ok(())
+    }
+
+    fn load_row(&mut self, line: &str) -> Result<(), ParseSchematicError> {
+        let line_number = self.first_row + self.window.len();
+        let mut numbers = vec![];
+        let cells = parse_row(line, line_number, &self.config, &mut numbers)?;
+        check_width(&cells, self.width, line_number)?;
+        self.width = Some(cells.len());
+        self.window.push_back(WindowRow { cells, numbers });
+        Ok(())
+    }
+
+    fn fail(&mut self, err: impl Into<StreamError>) -> Option<Result<StreamEvent, StreamError>> {
+        self.exhausted = true;
+        self.window.clear();
+        self.next_row = self.first_row;
+        Some(Err(err.into()))
+    }
+}
+
+impl<R: BufRead> Iterator for WindowSolver<R> {
+    type Item = Result<StreamEvent, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let radius = self.config.neighborhood.radius();
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            let loaded = self.first_row + self.window.len();
+            if self.next_row >= loaded && self.exhausted {
+                return None;
+            }
+            if self.next_row < loaded
+                && (self.exhausted || loaded > self.next_row.saturating_add(radius))
+            {
+                if let Err(err) = self.decide_row() {
+                    return self.fail(err);
+                }
+                self.next_row += 1;
+                while self.first_row.saturating_add(radius) < self.next_row
+                    && !self.window.is_empty()
+                {
+                    self.window.pop_front();
+                    self.first_row += 1;
+                }
+                continue;
+            }
+            match self.lines.next() {
+                None => self.exhausted = true,
+                Some(Err(err)) => return self.fail(err),
+                Some(Ok(line)) => {
+                    if let Err(err) = self.load_row(&line) {
+                        return self.fail(err);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gear::{NeighborCount, Reduction, SymbolFilter},
+        grid::Neighborhood,
+        Schematic,
+    };
+    use itertools::Itertools;
+    use rstest::*;
+    use std::io::Cursor;
+
+    fn in_memory_events(
+        input: &str,
+        config: &SchematicConfig,
+        rule: &GearRule,
+    ) -> Vec<StreamEvent> {
+        let schematic = Schematic::parse_with(input, config).unwrap();
+        let parts = schematic
+            .numbers
+            .iter()
+            .filter(|number| schematic.is_part_number(number))
+            .map(|number| (number.row, 0, StreamEvent::PartNumber(*number)));
+        let gears = schematic
+            .gear_values(rule)
+            .map(|gear| gear.unwrap())
+            .map(|(point, value)| (point.row, 1, StreamEvent::Gear { point, value }));
+        parts
+            .chain(gears)
+            .sorted_by_key(|(row, kind, _)| (*row, *kind))
+            .map(|(_, _, event)| event)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(include_str!("../puzzle_inputs/sample.txt"))]
+    #[case(include_str!("../puzzle_inputs/input_truncated.txt"))]
+    #[case(include_str!("../puzzle_inputs/input.txt"))]
+    fn test_matches_in_memory(
+        #[case] input: &str,
+        #[values(
+            Neighborhood::Moore,
+            Neighborhood::VonNeumann,
+            Neighborhood::Chebyshev(2)
+        )]
+        neighborhood: Neighborhood,
+        #[values(
+            GearRule::default(),
+            GearRule { symbols: SymbolFilter::Any, neighbors: NeighborCount::AtLeast(1), reduction: Reduction::Sum },
+        )]
+        rule: GearRule,
+    ) {
+        let config = SchematicConfig {
+            neighborhood,
+            ..Default::default()
+        };
+        let mut solver = solve_stream(Cursor::new(input), &config, &rule);
+        let mut streamed = vec![];
+        while let Some(event) = solver.next() {
+            assert!(solver.window_len() <= 2 * neighborhood.radius() + 1);
+            streamed.push(event.unwrap());
+        }
+        assert_eq!(streamed, in_memory_events(input, &config, &rule));
+    }
+
+    #[rstest]
+    #[case(Neighborhood::Chebyshev(usize::MAX))]
+    #[case(Neighborhood::Manhattan(usize::MAX))]
+    fn test_unbounded_radius(#[case] neighborhood: Neighborhood) {
+        let input = include_str!("../puzzle_inputs/sample.txt");
+        let config = SchematicConfig {
+            neighborhood,
+            ..Default::default()
+        };
+        let rule = GearRule::default();
+        let streamed: Vec<StreamEvent> = solve_stream(Cursor::new(input), &config, &rule)
+            .map(|event| event.unwrap())
+            .collect();
+        assert_eq!(streamed.len(), 10);
+        assert_eq!(streamed, in_memory_events(input, &config, &rule));
+    }
+
+    #[rstest]
+    fn test_sample_totals() {
+        let input = include_str!("../puzzle_inputs/sample.txt");
+        let (parts, gears) =
+            solve_stream(Cursor::new(input), &Default::default(), &Default::default())
+                .process_results(|events| {
+                    events.fold((0, 0), |(parts, gears), event| match event {
+                        StreamEvent::PartNumber(number) => (parts + number.value, gears),
+                        StreamEvent::Gear { value, .. } => (parts, gears + value),
+                    })
+                })
+                .unwrap();
+        assert_eq!(parts, 4361);
+        assert_eq!(gears, 467835);
+    }
+
+    #[rstest]
+    fn test_stops_at_parse_error() {
+        let input = "1*.\n...\n..\n2*.\n";
+        let results: Vec<bool> =
+            solve_stream(Cursor::new(input), &Default::default(), &Default::default())
+                .map(|event| event.is_ok())
+                .collect();
+        assert_eq!(results, vec![true, false]);
+    }
+
+    #[rstest]
+    #[case("1*.\n..\n", "2:3: row is 2 cells wide, expected 3")]
+    #[case(
+        "1*\n99999999999999999999\n",
+        "2:1: 99999999999999999999 does not fit in a 64-bit integer"
+    )]
+    #[case("9999999999*9999999999\n", "result does not fit in a 64-bit integer")]
+    fn test_error_kinds(#[case] input: &str, #[case] expected: &str) {
+        let err = solve_stream(Cursor::new(input), &Default::default(), &Default::default())
+            .find_map(Result::err)
+            .unwrap();
+        match err {
+            StreamError::Parse(_) => assert!(!expected.starts_with("result")),
+            StreamError::Overflow(_) => assert!(expected.starts_with("result")),
+            StreamError::Io(_) => panic!("unexpected I/O error"),
+        }
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -0,0 +1,52 @@
+use day3::{
+    generate::{generate, GeneratorConfig},
+    reference::differential,
+    sum_gear_ratios, sum_part_numbers, Schematic,
+};
+use rstest::*;
+use std::str::FromStr;
+
+fn library(input: &str) -> Option<(i64, i64)> {
+    let schematic = Schematic::from_str(input).ok()?;
+    Some((
+        sum_part_numbers(&schematic).ok()?,
+        sum_gear_ratios(&schematic).ok()?,
+    ))
+}
+
+#[rstest]
+#[case(include_str!("../puzzle_inputs/sample.txt"))]
+#[case(include_str!("../puzzle_inputs/input_truncated.txt"))]
+#[case(include_str!("../puzzle_inputs/input.txt"))]
+#[case("9999999999*9999999999")]
+#[case("3037000499*3037000499")]
+fn puzzle_inputs(#[case] input: &str) {
+    if let Err(counterexample) = differential(input, library) {
+        panic!("{counterexample}");
+    }
+}
+
+#[rstest]
+fn generated_inputs(
+    #[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64,
+    #[values(0.05, 0.3, 0.6)] number_density: f64,
+    #[values(0.05, 0.3)] symbol_density: f64,
+) {
+    let generated = generate(&GeneratorConfig {
+        width: 50,
+        height: 50,
+        number_density,
+        symbol_density,
+        planted_gears: 5,
+        seed,
+        ..Default::default()
+    })
+    .unwrap();
+    if let Err(counterexample) = differential(&generated.text, library) {
+        panic!("{counterexample}");
+    }
+    assert_eq!(
+        library(&generated.text),
+        Some((generated.part_number_sum, generated.gear_ratio_sum))
+    );
+}